@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use cpzkp::{Group, Point, get_constants, solve_zk_challenge_s, verify};
+use cpzkp::{Group, Point, get_constants, solve_zk_challenge_s, verify, verify_batch_shared_generators};
 use num_bigint::BigUint;
 use rand::random;
 
@@ -90,6 +90,11 @@ fn benchmark_verification(c: &mut Criterion) {
         b.iter(|| verify(black_box(&params)))
     });
 
+    let batch = vec![params.clone(); 100];
+    group.bench_function("verify_batch_shared_generators_100", |b| {
+        b.iter(|| verify_batch_shared_generators(black_box(&batch)))
+    });
+
     group.finish();
 }
 
@@ -1,5 +1,5 @@
 use pyo3::prelude::*;
-use cpzkp::{Group, Point, get_constants, solve_zk_challenge_s, Error};
+use cpzkp::{Group, Point, Secret, get_constants, solve_zk_challenge_s_secret, challenge_from_transcript, Error};
 use num_bigint::BigUint;
 use serde_json::{json, to_string};
 
@@ -12,6 +12,7 @@ pub struct KeyPair {
     h: Point,
     y1: Point,
     y2: Point,
+    x_secret: Secret,
 }
 
 #[pymethods]
@@ -23,15 +24,16 @@ impl KeyPair {
             "elliptic" => Group::EllipticCurve,
             #[cfg(feature = "curve25519")]
             "curve25519" => Group::Curve25519,
+            "secp256k1" => Group::Secp256k1,
             _ => return Err(pyo3::exceptions::PyValueError::new_err("Invalid group type")),
         };
 
         let (p, q, g, h) = get_constants(&group)
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-        
+
         let x_secret = BigUint::from_bytes_be(&rand::random::<[u8; 32]>());
         let y1 = g.scale(x_secret.clone());
-        let y2 = h.scale(x_secret);
+        let y2 = h.scale(x_secret.clone());
 
         Ok(KeyPair {
             group,
@@ -41,6 +43,7 @@ impl KeyPair {
             h,
             y1,
             y2,
+            x_secret: Secret::new(x_secret),
         })
     }
 
@@ -80,13 +83,22 @@ impl Proof {
         let r1 = keypair.g.scale(k.clone());
         let r2 = keypair.h.scale(k.clone());
 
-        let c = BigUint::from_bytes_be(&rand::random::<[u8; 32]>());
-        let s = solve_zk_challenge_s(
-            &BigUint::from_bytes_be(message.as_bytes()),
-            &k,
-            &c,
+        // Fiat-Shamir: derive `c` from the transcript of public parameters and
+        // commitments instead of drawing it independently, so a forged proof
+        // can't pick `s` first and then pick a convenient `c`, and binding
+        // `message` into the transcript keeps a proof from being replayed
+        // against a different statement.
+        let c = challenge_from_transcript(
+            &keypair.g,
+            &keypair.h,
+            &keypair.y1,
+            &keypair.y2,
+            &r1,
+            &r2,
+            Some(message.as_bytes()),
             &keypair.q,
         );
+        let s = solve_zk_challenge_s_secret(&keypair.x_secret, &Secret::new(k), &c, &keypair.q);
 
         Ok(Proof {
             group: keypair.group.clone(),
@@ -119,7 +131,14 @@ impl Proof {
     }
 
     pub fn verify(&self) -> PyResult<bool> {
-        let params = cpzkp::VerificationParams {
+        cpzkp::verify(&self.to_verification_params())
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl Proof {
+    fn to_verification_params(&self) -> cpzkp::VerificationParams {
+        cpzkp::VerificationParams {
             r1: self.r1.clone(),
             r2: self.r2.clone(),
             y1: self.y1.clone(),
@@ -129,14 +148,26 @@ impl Proof {
             c: self.c.clone(),
             s: self.s.clone(),
             p: self.p.clone(),
-        };
-        cpzkp::verify(&params).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        }
     }
 }
 
+/// Verifies many proofs sharing one `g`/`h`/`p` (e.g. a stream of
+/// authentication attempts against one system's parameters) in a single
+/// randomized multi-exponentiation instead of one `Proof::verify` call per
+/// proof. See [`cpzkp::verify_batch_shared_generators`] for the algorithm.
+#[pyfunction]
+fn verify_proof_batch(proofs: Vec<PyRef<Proof>>) -> PyResult<bool> {
+    let params: Vec<cpzkp::VerificationParams> =
+        proofs.iter().map(|proof| proof.to_verification_params()).collect();
+    cpzkp::verify_batch_shared_generators(&params)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
 #[pymodule]
 fn cpzkp_py(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<KeyPair>()?;
     m.add_class::<Proof>()?;
+    m.add_function(wrap_pyfunction!(verify_proof_batch, m)?)?;
     Ok(())
 } 
\ No newline at end of file
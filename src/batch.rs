@@ -0,0 +1,310 @@
+//! Batch verification of many Chaum-Pedersen proofs via random-linear-
+//! combination (RLC) aggregation, with the per-proof exponentiations
+//! parallelized across CPU cores.
+//!
+//! Checking `n` proofs independently costs `n` multi-exponentiations. RLC
+//! batching draws an independent random weight `ρ_i` per proof and checks
+//! one aggregated pair of equations instead — `∏ r1_i^{ρ_i} == ∏ (g_i^{s_i}
+//! · y1_i^{c_i})^{ρ_i}` and the analogous one for `(h_i, y2_i, r2_i)` — so a
+//! forged proof in the batch is caught with overwhelming probability over
+//! the choice of `ρ_i`, at roughly the cost of one multi-exponentiation
+//! instead of `n`. This generalizes [`VerifierSession::verify_all`](crate::session::VerifierSession::verify_all)
+//! from a single session's rounds (which all share one `g`/`h`/`y1`/`y2`) to
+//! an arbitrary slice of independently-parameterized proofs.
+
+use crate::commitment::{point_combine, point_pow};
+use crate::types::{Error, Point, VerificationParams};
+use num_bigint::BigUint;
+use std::thread;
+
+/// Computes the weighted `r1`/`g^s·y1^c` pair and the weighted `r2`/`h^s·y2^c`
+/// pair for one proof, the unit of work fanned out across the worker pool.
+///
+/// The two equations are kept as two separate pairs rather than combined
+/// into one group element: combining `r1·r2` and `(g^s y1^c)·(h^s y2^c)`
+/// before comparison would let a forger pick an arbitrary `r1` and an
+/// unrelated `y1`/`y2` with no known discrete log, then solve algebraically
+/// for `r2` so the *combined* equation holds even though neither real
+/// equation does. Requiring both pairs to match independently (see
+/// [`verify_batch`]) closes that gap, the same way
+/// [`verify_batch_shared_generators`] and
+/// [`VerifierSession::verify_all`](crate::session::VerifierSession::verify_all)
+/// already do.
+fn per_proof_rhs(
+    params: &VerificationParams,
+    rho: &BigUint,
+) -> Result<((Point, Point), (Point, Point)), Error> {
+    let r1_rho = point_pow(&params.r1, rho, &params.p)?;
+    let r2_rho = point_pow(&params.r2, rho, &params.p)?;
+
+    let g_s = point_pow(&params.g, &params.s, &params.p)?;
+    let y1_c = point_pow(&params.y1, &params.c, &params.p)?;
+    let rhs1_rho = point_pow(&point_combine(&g_s, &y1_c, &params.p)?, rho, &params.p)?;
+
+    let h_s = point_pow(&params.h, &params.s, &params.p)?;
+    let y2_c = point_pow(&params.y2, &params.c, &params.p)?;
+    let rhs2_rho = point_pow(&point_combine(&h_s, &y2_c, &params.p)?, rho, &params.p)?;
+
+    Ok(((r1_rho, rhs1_rho), (r2_rho, rhs2_rho)))
+}
+
+/// Verifies a batch of Chaum-Pedersen proofs, returning `true` only if
+/// every one of them is valid. Draws fresh random weights per call, so the
+/// result cannot be precomputed by an adversary ahead of time.
+pub fn verify_batch(proofs: &[VerificationParams]) -> Result<bool, Error> {
+    if proofs.is_empty() {
+        return Ok(true);
+    }
+
+    let worker_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(proofs.len());
+
+    // VerificationParams carries the modulus `p` but not the group order, so
+    // the random weight is drawn as a fresh 128-bit value instead of being
+    // reduced modulo the order; this keeps the check sound (the same `ρ_i`
+    // is raised on both sides of the aggregated equation) without needing
+    // the order here.
+    let weights: Vec<BigUint> = proofs
+        .iter()
+        .map(|_| BigUint::from_bytes_be(&rand::random::<[u8; 16]>()))
+        .collect();
+
+    let results: Vec<Result<((Point, Point), (Point, Point)), Error>> = thread::scope(|scope| {
+        let chunk_size = proofs.len().div_ceil(worker_count.max(1));
+        let mut handles = Vec::new();
+
+        for (chunk, weight_chunk) in proofs.chunks(chunk_size).zip(weights.chunks(chunk_size)) {
+            handles.push(scope.spawn(move || {
+                chunk
+                    .iter()
+                    .zip(weight_chunk)
+                    .map(|(params, rho)| per_proof_rhs(params, rho))
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+    });
+
+    let mut agg_lhs1: Option<Point> = None;
+    let mut agg_rhs1: Option<Point> = None;
+    let mut agg_lhs2: Option<Point> = None;
+    let mut agg_rhs2: Option<Point> = None;
+
+    for (params, result) in proofs.iter().zip(results) {
+        let ((lhs1, rhs1), (lhs2, rhs2)) = result?;
+        agg_lhs1 = Some(match agg_lhs1 {
+            Some(acc) => point_combine(&acc, &lhs1, &params.p)?,
+            None => lhs1,
+        });
+        agg_rhs1 = Some(match agg_rhs1 {
+            Some(acc) => point_combine(&acc, &rhs1, &params.p)?,
+            None => rhs1,
+        });
+        agg_lhs2 = Some(match agg_lhs2 {
+            Some(acc) => point_combine(&acc, &lhs2, &params.p)?,
+            None => lhs2,
+        });
+        agg_rhs2 = Some(match agg_rhs2 {
+            Some(acc) => point_combine(&acc, &rhs2, &params.p)?,
+            None => rhs2,
+        });
+    }
+
+    Ok(agg_lhs1 == agg_rhs1 && agg_lhs2 == agg_rhs2)
+}
+
+/// Checks a single proof the same way [`verify_batch`] would for a batch of
+/// one, i.e. without a random weight (`ρ = 1`). Equivalent to the
+/// interactive `verify`, just expressed in terms of this module's own
+/// [`per_proof_rhs`] instead of the root `VerificationParams`/`verify`
+/// (a distinct, field-compatible type from `crate::types`).
+fn verify_single(params: &VerificationParams) -> Result<bool, Error> {
+    let ((lhs1, rhs1), (lhs2, rhs2)) = per_proof_rhs(params, &BigUint::from(1u32))?;
+    Ok(lhs1 == rhs1 && lhs2 == rhs2)
+}
+
+/// Verifies `proofs` as a batch and, if the aggregate check fails,
+/// re-checks each proof individually to report which one(s) are invalid.
+/// Prefer [`verify_batch`] when only a pass/fail answer is needed — the
+/// per-proof fallback here costs as much as the naive one-at-a-time
+/// verification it's meant to replace.
+pub fn verify_batch_identify_failures(proofs: &[VerificationParams]) -> Result<Vec<usize>, Error> {
+    if verify_batch(proofs)? {
+        return Ok(Vec::new());
+    }
+
+    proofs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, params)| match verify_single(params) {
+            Ok(true) => None,
+            Ok(false) => Some(Ok(i)),
+            Err(e) => Some(Err(e)),
+        })
+        .collect()
+}
+
+/// Verifies a batch of proofs that all share the same `g`/`h` generators and
+/// modulus `p` (e.g. many proofs over one system's parameters, as produced
+/// by [`crate::get_constants`]), exploiting that to cut [`verify_batch`]'s
+/// roughly `8N` scalar multiplications to `4N` plus two fixed-base ones:
+/// since `g`/`h` don't vary across proofs, `Σ ρ_i·(g^{s_i})` collapses to a
+/// single `g^{Σ ρ_i·s_i}` instead of `N` separate `g^{s_i}` calls (and
+/// likewise for `h`), leaving only the per-proof `y1_i^{ρ_i·c_i}`/
+/// `y2_i^{ρ_i·c_i}` and `r1_i^{ρ_i}`/`r2_i^{ρ_i}` terms (which genuinely
+/// differ per proof) to compute individually.
+///
+/// Returns [`Error::PointTypeMismatch`] if any proof in `proofs` uses a
+/// different `g`, `h`, or `p` than the first. Use [`verify_batch`] instead
+/// for a batch of proofs over heterogeneous parameters.
+pub fn verify_batch_shared_generators(proofs: &[VerificationParams]) -> Result<bool, Error> {
+    if proofs.is_empty() {
+        return Ok(true);
+    }
+
+    let (g, h, p) = (&proofs[0].g, &proofs[0].h, &proofs[0].p);
+    if proofs.iter().any(|params| &params.g != g || &params.h != h || &params.p != p) {
+        return Err(Error::PointTypeMismatch);
+    }
+
+    // A fresh random weight per proof, same role as in `verify_batch`.
+    let weights: Vec<BigUint> = proofs
+        .iter()
+        .map(|_| BigUint::from_bytes_be(&rand::random::<[u8; 16]>()))
+        .collect();
+
+    let summed_s: BigUint = proofs
+        .iter()
+        .zip(&weights)
+        .map(|(params, rho)| &params.s * rho)
+        .sum();
+    let mut rhs1 = point_pow(g, &summed_s, p)?;
+    let mut rhs2 = point_pow(h, &summed_s, p)?;
+
+    let mut lhs1: Option<Point> = None;
+    let mut lhs2: Option<Point> = None;
+
+    for (params, rho) in proofs.iter().zip(&weights) {
+        let rho_c = rho * &params.c;
+        let y1_rho_c = point_pow(&params.y1, &rho_c, p)?;
+        let y2_rho_c = point_pow(&params.y2, &rho_c, p)?;
+        rhs1 = point_combine(&rhs1, &y1_rho_c, p)?;
+        rhs2 = point_combine(&rhs2, &y2_rho_c, p)?;
+
+        let r1_rho = point_pow(&params.r1, rho, p)?;
+        let r2_rho = point_pow(&params.r2, rho, p)?;
+        lhs1 = Some(match lhs1 {
+            Some(acc) => point_combine(&acc, &r1_rho, p)?,
+            None => r1_rho,
+        });
+        lhs2 = Some(match lhs2 {
+            Some(acc) => point_combine(&acc, &r2_rho, p)?,
+            None => r2_rho,
+        });
+    }
+
+    Ok(lhs1.unwrap() == rhs1 && lhs2.unwrap() == rhs2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exponentiates_points, get_constants_scalar, solve_zk_challenge_s};
+
+    fn make_params(x: u32, k: u32) -> VerificationParams {
+        let (p, q, g, h) = get_constants_scalar();
+        let x = BigUint::from(x);
+        let k = BigUint::from(k);
+        let (y1, y2) = exponentiates_points(&x, &g, &h, &p).unwrap();
+        let (r1, r2) = exponentiates_points(&k, &g, &h, &p).unwrap();
+        let c = BigUint::from(17u32) % &q;
+        let s = solve_zk_challenge_s(&x, &k, &c, &q);
+
+        VerificationParams { r1, r2, y1, y2, g, h, c, s, p }
+    }
+
+    #[test]
+    fn verify_batch_accepts_all_valid_proofs() {
+        let proofs = vec![make_params(11, 22), make_params(33, 44), make_params(55, 66)];
+        assert!(verify_batch(&proofs).unwrap());
+    }
+
+    #[test]
+    fn verify_batch_rejects_a_single_forged_proof() {
+        let mut proofs = vec![make_params(11, 22), make_params(33, 44)];
+        proofs[1].s = BigUint::from(1u32);
+        assert!(!verify_batch(&proofs).unwrap());
+    }
+
+    #[test]
+    fn verify_batch_identify_failures_finds_the_offending_proof() {
+        let mut proofs = vec![make_params(11, 22), make_params(33, 44), make_params(55, 66)];
+        proofs[1].s = BigUint::from(1u32);
+        assert_eq!(verify_batch_identify_failures(&proofs).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn verify_batch_identify_failures_empty_when_all_valid() {
+        let proofs = vec![make_params(11, 22), make_params(33, 44)];
+        assert!(verify_batch_identify_failures(&proofs).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_single_and_identify_failures_reject_a_forgery_where_both_equations_fail() {
+        // A forged proof built from an arbitrary r1 and unrelated y1/y2 (no
+        // known discrete log), with r2 solved for so that only the *combined*
+        // r1*r2 vs (g^s y1^c)*(h^s y2^c) product matches — neither the real
+        // r1/g,y1 equation nor the real r2/h,y2 equation holds on its own.
+        // verify_single (and therefore verify_batch_identify_failures' per-
+        // proof fallback) must reject this, not just the aggregated
+        // verify_batch check.
+        let (p, _q, g, h) = crate::get_constants_scalar();
+
+        let r1 = Point::Scalar(BigUint::from(7u32));
+        let y1 = Point::Scalar(BigUint::from(13u32));
+        let y2 = Point::Scalar(BigUint::from(17u32));
+        let s = BigUint::from(5u32);
+        let c = BigUint::from(3u32);
+
+        let combined_rhs = point_combine(
+            &point_combine(&point_pow(&g, &s, &p).unwrap(), &point_pow(&y1, &c, &p).unwrap(), &p).unwrap(),
+            &point_combine(&point_pow(&h, &s, &p).unwrap(), &point_pow(&y2, &c, &p).unwrap(), &p).unwrap(),
+            &p,
+        )
+        .unwrap();
+        let Point::Scalar(r1_scalar) = &r1 else { unreachable!() };
+        let Point::Scalar(combined_scalar) = &combined_rhs else { unreachable!() };
+        let r1_inv = r1_scalar.modpow(&(&p - BigUint::from(2u32)), &p);
+        let r2 = Point::Scalar((combined_scalar * r1_inv) % &p);
+
+        let forged = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(!verify_single(&forged).unwrap());
+
+        let proofs = vec![make_params(11, 22), forged];
+        assert!(!verify_batch(&proofs).unwrap());
+        assert_eq!(verify_batch_identify_failures(&proofs).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn verify_batch_shared_generators_accepts_all_valid_proofs() {
+        let proofs = vec![make_params(11, 22), make_params(33, 44), make_params(55, 66)];
+        assert!(verify_batch_shared_generators(&proofs).unwrap());
+    }
+
+    #[test]
+    fn verify_batch_shared_generators_rejects_a_forged_proof() {
+        let mut proofs = vec![make_params(11, 22), make_params(33, 44)];
+        proofs[1].s = BigUint::from(1u32);
+        assert!(!verify_batch_shared_generators(&proofs).unwrap());
+    }
+
+    #[test]
+    fn verify_batch_shared_generators_rejects_mismatched_parameters() {
+        let mut proofs = vec![make_params(11, 22), make_params(33, 44)];
+        proofs[1].g = Point::Scalar(BigUint::from(999u32));
+        assert!(verify_batch_shared_generators(&proofs).is_err());
+    }
+}
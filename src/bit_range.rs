@@ -0,0 +1,242 @@
+//! Bit-decomposition range proofs over a Pedersen commitment, using a
+//! Chaum-Pedersen OR-proof per bit.
+//!
+//! This is a different construction from [`RangeProof`](crate::range_sig::RangeProof)'s
+//! signature-based (CCS08) scheme: here there is no pre-signing authority,
+//! and hiding which branch is true comes from the classic Cramer-Damgård-
+//! Schoenmakers OR-proof instead of a blinded signature. Both share the
+//! same goal (prove a committed value lies in a range without revealing
+//! it) but suit different trust models — this one needs no setup beyond
+//! the commitment generators.
+//!
+//! Commit to `v` as `C = g^v · h^r`, decompose `v = Σ b_i·2^i`, and commit
+//! to each bit as `C_i = g^{b_i}·h^{r_i}` with `r = Σ r_i·2^i`. For each
+//! `C_i`, prove `C_i = h^{r_i}` (bit 0) OR `C_i/g = h^{r_i}` (bit 1) without
+//! revealing which. Each bit's OR-proof derives its own Fiat-Shamir
+//! challenge `c` over that bit's commitments, and its two sub-challenges
+//! sum to `c mod order`.
+
+use crate::commitment::{point_combine, point_pow};
+use crate::transcript::Transcript;
+use crate::types::{Error, Point};
+use num_bigint::BigUint;
+
+/// An OR-proof that a single bit commitment opens to `0` or `1`, without
+/// revealing which.
+struct BitProof {
+    a0: Point,
+    a1: Point,
+    c0: BigUint,
+    c1: BigUint,
+    z0: BigUint,
+    z1: BigUint,
+}
+
+/// A bit-decomposition range proof that a Pedersen-committed value lies in
+/// `[0, 2^n)`.
+pub struct BitRangeProof {
+    commitment: Point,
+    bit_commitments: Vec<Point>,
+    bit_proofs: Vec<BitProof>,
+}
+
+fn invert(point: &Point, p: &BigUint, order: &BigUint) -> Result<Point, Error> {
+    point_pow(point, &(order - BigUint::from(1u32)), p)
+}
+
+fn random_scalar(order: &BigUint) -> BigUint {
+    BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % order
+}
+
+/// Derives the shared challenge `c` that binds an OR-proof's two branches,
+/// absorbing the bases, the bit commitment, and both branch commitments.
+fn or_proof_challenge(g: &Point, h: &Point, c_i: &Point, a0: &Point, a1: &Point, order: &BigUint) -> BigUint {
+    let mut transcript = Transcript::new(b"cpzkp/bit-range");
+    transcript.absorb_point(g);
+    transcript.absorb_point(h);
+    transcript.absorb_point(c_i);
+    transcript.absorb_point(a0);
+    transcript.absorb_point(a1);
+    transcript.challenge(order)
+}
+
+impl BitRangeProof {
+    /// Proves that `v` lies in `[0, 2^n)` under the Pedersen generators
+    /// `(g, h)` in a group with modulus `p` and order `order`.
+    pub fn prove(
+        v: &BigUint,
+        n: u32,
+        g: &Point,
+        h: &Point,
+        p: &BigUint,
+        order: &BigUint,
+    ) -> Result<Self, Error> {
+        let bits: Vec<bool> = (0..n)
+            .map(|i| (v >> (i as usize)) & BigUint::from(1u32) == BigUint::from(1u32))
+            .collect();
+
+        let blinds: Vec<BigUint> = (0..n).map(|_| random_scalar(order)).collect();
+        let r: BigUint = blinds
+            .iter()
+            .enumerate()
+            .fold(BigUint::from(0u32), |acc, (i, r_i)| {
+                acc + r_i * (BigUint::from(1u32) << i)
+            })
+            % order;
+
+        let commitment = point_combine(
+            &point_pow(g, v, p)?,
+            &point_pow(h, &r, p)?,
+            p,
+        )?;
+
+        let mut bit_commitments = Vec::with_capacity(n as usize);
+        let mut bit_proofs = Vec::with_capacity(n as usize);
+
+        for (bit, r_i) in bits.into_iter().zip(blinds.into_iter()) {
+            let b_i = if bit { BigUint::from(1u32) } else { BigUint::from(0u32) };
+            let c_i = point_combine(&point_pow(g, &b_i, p)?, &point_pow(h, &r_i, p)?, p)?;
+            let target1 = point_combine(&c_i, &invert(g, p, order)?, p)?;
+
+            let (a0, a1, c0, c1, z0, z1) = if bit {
+                // True branch is bit=1: simulate bit=0 (target is c_i itself).
+                let c0_sim = random_scalar(order);
+                let z0_sim = random_scalar(order);
+                let a0_sim = point_combine(
+                    &point_pow(h, &z0_sim, p)?,
+                    &invert(&point_pow(&c_i, &c0_sim, p)?, p, order)?,
+                    p,
+                )?;
+
+                let w1 = random_scalar(order);
+                let a1_honest = point_pow(h, &w1, p)?;
+
+                let c = or_proof_challenge(g, h, &c_i, &a0_sim, &a1_honest, order);
+                let c1_honest = (&c + order - &c0_sim) % order;
+                let z1_honest = (w1 + &c1_honest * &r_i) % order;
+
+                (a0_sim, a1_honest, c0_sim, c1_honest, z0_sim, z1_honest)
+            } else {
+                // True branch is bit=0 (target is c_i itself); simulate bit=1.
+                let c1_sim = random_scalar(order);
+                let z1_sim = random_scalar(order);
+                let a1_sim = point_combine(
+                    &point_pow(h, &z1_sim, p)?,
+                    &invert(&point_pow(&target1, &c1_sim, p)?, p, order)?,
+                    p,
+                )?;
+
+                let w0 = random_scalar(order);
+                let a0_honest = point_pow(h, &w0, p)?;
+
+                let c = or_proof_challenge(g, h, &c_i, &a0_honest, &a1_sim, order);
+                let c0_honest = (&c + order - &c1_sim) % order;
+                let z0_honest = (w0 + &c0_honest * &r_i) % order;
+
+                (a0_honest, a1_sim, c0_honest, c1_sim, z0_honest, z1_sim)
+            };
+
+            bit_commitments.push(c_i);
+            bit_proofs.push(BitProof { a0, a1, c0, c1, z0, z1 });
+        }
+
+        Ok(BitRangeProof { commitment, bit_commitments, bit_proofs })
+    }
+
+    /// Verifies that `∏ C_i^{2^i} == C` and every bit commitment's
+    /// OR-proof.
+    pub fn verify(&self, g: &Point, h: &Point, p: &BigUint, order: &BigUint) -> Result<bool, Error> {
+        let mut recombined: Option<Point> = None;
+        for (i, c_i) in self.bit_commitments.iter().enumerate() {
+            let weighted = point_pow(c_i, &(BigUint::from(1u32) << i), p)?;
+            recombined = Some(match recombined {
+                Some(acc) => point_combine(&acc, &weighted, p)?,
+                None => weighted,
+            });
+        }
+        if recombined != Some(self.commitment.clone()) {
+            return Ok(false);
+        }
+
+        for (c_i, proof) in self.bit_commitments.iter().zip(&self.bit_proofs) {
+            let c = or_proof_challenge(g, h, c_i, &proof.a0, &proof.a1, order);
+            if (&proof.c0 + &proof.c1) % order != c {
+                return Ok(false);
+            }
+
+            let lhs0 = point_pow(h, &proof.z0, p)?;
+            let rhs0 = point_combine(&proof.a0, &point_pow(c_i, &proof.c0, p)?, p)?;
+            if lhs0 != rhs0 {
+                return Ok(false);
+            }
+
+            let target1 = point_combine(c_i, &invert(g, p, order)?, p)?;
+            let lhs1 = point_pow(h, &proof.z1, p)?;
+            let rhs1 = point_combine(&proof.a1, &point_pow(&target1, &proof.c1, p)?, p)?;
+            if lhs1 != rhs1 {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Free-function form of [`BitRangeProof::prove`], for callers that prefer
+/// a `prove_range(x, n, ...)` call over the `BitRangeProof::prove` method.
+pub fn prove_range(
+    x: &BigUint,
+    n: u32,
+    g: &Point,
+    h: &Point,
+    p: &BigUint,
+    order: &BigUint,
+) -> Result<BitRangeProof, Error> {
+    BitRangeProof::prove(x, n, g, h, p, order)
+}
+
+/// Free-function form of [`BitRangeProof::verify`], the counterpart to
+/// [`prove_range`].
+pub fn verify_range(
+    proof: &BitRangeProof,
+    g: &Point,
+    h: &Point,
+    p: &BigUint,
+    order: &BigUint,
+) -> Result<bool, Error> {
+    proof.verify(g, h, p, order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_constants_scalar;
+
+    #[test]
+    fn bit_range_proof_roundtrip() {
+        let (p, q, g, h) = get_constants_scalar();
+        let v = BigUint::from(13u32); // 1101
+
+        let proof = BitRangeProof::prove(&v, 8, &g, &h, &p, &q).unwrap();
+        assert!(proof.verify(&g, &h, &p, &q).unwrap());
+    }
+
+    #[test]
+    fn bit_range_proof_rejects_tampered_commitment() {
+        let (p, q, g, h) = get_constants_scalar();
+        let v = BigUint::from(200u32);
+
+        let mut proof = BitRangeProof::prove(&v, 8, &g, &h, &p, &q).unwrap();
+        proof.commitment = point_pow(&g, &BigUint::from(999u32), &p).unwrap();
+        assert!(!proof.verify(&g, &h, &p, &q).unwrap());
+    }
+
+    #[test]
+    fn prove_range_and_verify_range_roundtrip() {
+        let (p, q, g, h) = get_constants_scalar();
+        let v = BigUint::from(42u32);
+
+        let proof = prove_range(&v, 8, &g, &h, &p, &q).unwrap();
+        assert!(verify_range(&proof, &g, &h, &p, &q).unwrap());
+    }
+}
@@ -0,0 +1,166 @@
+//! Pedersen multi-message commitments with a Schnorr proof of opening.
+//!
+//! Built on the crate's existing `Point`/`Group` abstractions, this mirrors
+//! the `ped92` commitment scheme: a vector of independent generators
+//! `(g_1, …, g_n)` plus a blinding generator `h` commit to a message vector
+//! as `C = Π g_i^{m_i} · h^r`, and `CommitmentProof` lets a prover show it
+//! knows the opening `(m_1, …, m_n, r)` without revealing it.
+
+use crate::transcript::Transcript;
+use crate::types::{Error, Point};
+use num::traits::One;
+use num_bigint::BigUint;
+
+/// Raises `base` to `exp` within the group `base` belongs to, reducing
+/// modulo `p` for the scalar group or applying scalar multiplication for
+/// the elliptic curve group.
+pub(crate) fn point_pow(base: &Point, exp: &BigUint, p: &BigUint) -> Result<Point, Error> {
+    match base {
+        Point::Scalar(g) => Ok(Point::Scalar(g.modpow(exp, p))),
+        Point::ECPoint(gx, gy) => {
+            let g = secp256k1::Point::from_bigint(gx.clone(), gy.clone());
+            match g.scale(exp.clone()) {
+                secp256k1::Point::Coor { x, y, .. } => Ok(Point::ECPoint(x.number, y.number)),
+                _ => Err(Error::EllipticCurveError(
+                    "Zero point reached in multiplication".to_string(),
+                )),
+            }
+        }
+        Point::Ristretto(_) => Err(Error::PointTypeMismatch),
+        Point::Identity => Err(Error::PointTypeMismatch),
+    }
+}
+
+/// Combines two group elements: multiplication for the scalar group,
+/// point addition for the elliptic curve group.
+pub(crate) fn point_combine(a: &Point, b: &Point, p: &BigUint) -> Result<Point, Error> {
+    match (a, b) {
+        (Point::Scalar(a), Point::Scalar(b)) => {
+            Ok(Point::Scalar((a * b).modpow(&BigUint::one(), p)))
+        }
+        (Point::ECPoint(ax, ay), Point::ECPoint(bx, by)) => {
+            let a = secp256k1::Point::from_bigint(ax.clone(), ay.clone());
+            let b = secp256k1::Point::from_bigint(bx.clone(), by.clone());
+            match a + b {
+                secp256k1::Point::Coor { x, y, .. } => Ok(Point::ECPoint(x.number, y.number)),
+                _ => Err(Error::EllipticCurveError(
+                    "Zero point reached in addition".to_string(),
+                )),
+            }
+        }
+        _ => Err(Error::PointTypeMismatch),
+    }
+}
+
+/// Public parameters for an `n`-message Pedersen commitment: one generator
+/// per message slot plus a blinding generator `h`, all in the same group.
+#[derive(Clone)]
+pub struct CSMultiParams {
+    /// Per-message generators `g_1, …, g_n`.
+    pub generators: Vec<Point>,
+    /// Blinding generator `h`.
+    pub h: Point,
+    /// Modulus of the underlying group (prime field modulus, or the
+    /// elliptic curve's field prime).
+    pub p: BigUint,
+    /// Order of the group, used to reduce the proof's response scalars.
+    pub q: BigUint,
+}
+
+impl CSMultiParams {
+    /// Builds the parameter set from `n` message generators, a blinding
+    /// generator, and the group's modulus/order.
+    pub fn new(generators: Vec<Point>, h: Point, p: BigUint, q: BigUint) -> Self {
+        CSMultiParams { generators, h, p, q }
+    }
+
+    /// Commits to `messages` under blinding `r`: `C = Π g_i^{m_i} · h^r`.
+    pub fn commit(&self, messages: &[BigUint], r: &BigUint) -> Result<Point, Error> {
+        if messages.len() != self.generators.len() {
+            return Err(Error::InvalidArguments);
+        }
+
+        let mut acc = point_pow(&self.h, r, &self.p)?;
+        for (g_i, m_i) in self.generators.iter().zip(messages) {
+            acc = point_combine(&acc, &point_pow(g_i, m_i, &self.p)?, &self.p)?;
+        }
+        Ok(acc)
+    }
+}
+
+/// A non-interactive zero-knowledge proof of knowledge of a commitment's
+/// opening `(m_1, …, m_n, r)`.
+pub struct CommitmentProof {
+    /// The prover's blinded commitment `T = Π g_i^{t_i} · h^{t_r}`.
+    t: Point,
+    /// Fiat-Shamir challenge.
+    c: BigUint,
+    /// Responses `z_i = t_i + c·m_i mod q` for each message slot.
+    z: Vec<BigUint>,
+    /// Response `z_r = t_r + c·r mod q` for the blinding factor.
+    z_r: BigUint,
+}
+
+impl CommitmentProof {
+    /// Proves knowledge of the opening `(messages, r)` of `commitment`
+    /// under `params`.
+    pub fn prove(
+        params: &CSMultiParams,
+        commitment: &Point,
+        messages: &[BigUint],
+        r: &BigUint,
+    ) -> Result<Self, Error> {
+        if messages.len() != params.generators.len() {
+            return Err(Error::InvalidArguments);
+        }
+
+        let blindings: Vec<BigUint> = (0..messages.len())
+            .map(|_| BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % &params.q)
+            .collect();
+        let t_r = BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % &params.q;
+
+        let mut t = point_pow(&params.h, &t_r, &params.p)?;
+        for (g_i, t_i) in params.generators.iter().zip(&blindings) {
+            t = point_combine(&t, &point_pow(g_i, t_i, &params.p)?, &params.p)?;
+        }
+
+        let mut transcript = Transcript::new(b"cpzkp/commitment-opening");
+        for g_i in &params.generators {
+            transcript.absorb_point(g_i);
+        }
+        transcript.absorb_point(&params.h);
+        transcript.absorb_point(commitment);
+        transcript.absorb_point(&t);
+        let c = transcript.challenge(&params.q);
+
+        let z: Vec<BigUint> = blindings
+            .iter()
+            .zip(messages)
+            .map(|(t_i, m_i)| (t_i + &c * m_i) % &params.q)
+            .collect();
+        let z_r = (t_r + &c * r) % &params.q;
+
+        Ok(CommitmentProof { t, c, z, z_r })
+    }
+
+    /// Verifies the proof against `commitment` under `params`, checking
+    /// `Π g_i^{z_i} · h^{z_r} == T · C^c`.
+    pub fn verify(&self, params: &CSMultiParams, commitment: &Point) -> Result<bool, Error> {
+        if self.z.len() != params.generators.len() {
+            return Err(Error::InvalidArguments);
+        }
+
+        let mut lhs = point_pow(&params.h, &self.z_r, &params.p)?;
+        for (g_i, z_i) in params.generators.iter().zip(&self.z) {
+            lhs = point_combine(&lhs, &point_pow(g_i, z_i, &params.p)?, &params.p)?;
+        }
+
+        let rhs = point_combine(
+            &self.t,
+            &point_pow(commitment, &self.c, &params.p)?,
+            &params.p,
+        )?;
+
+        Ok(lhs == rhs)
+    }
+}
@@ -0,0 +1,315 @@
+//! A pluggable curve backend: the `Curve` trait abstracts the generator,
+//! order, prime, scalar multiplication, point addition, and (de)serialization
+//! of a group, so that new curves can be added as new implementors instead
+//! of by editing every `match` in `get_constants`/`exponentiates_points`/
+//! `verify`.
+
+use crate::types::{Error, Point};
+use num_bigint::BigUint;
+
+/// A cyclic group usable as a `Curve` backend for the Chaum-Pedersen
+/// protocol.
+pub trait Curve {
+    /// Human-readable curve name, e.g. `"secp256k1"`.
+    fn name(&self) -> &'static str;
+
+    /// The field prime the curve's coordinates live in.
+    fn prime(&self) -> BigUint;
+
+    /// The order of the curve's generator subgroup.
+    fn order(&self) -> BigUint;
+
+    /// The curve's base generator point.
+    fn generator(&self) -> Point;
+
+    /// Adds two points on the curve.
+    fn add(&self, a: &Point, b: &Point) -> Result<Point, Error>;
+
+    /// Multiplies a point by a scalar.
+    fn scalar_mul(&self, point: &Point, scalar: &BigUint) -> Result<Point, Error>;
+
+    /// Serializes a point to bytes in this curve's canonical encoding.
+    fn serialize(&self, point: &Point) -> Vec<u8>;
+
+    /// Deserializes a point from this curve's canonical encoding.
+    fn deserialize(&self, bytes: &[u8]) -> Result<Point, Error>;
+}
+
+/// The secp256k1 backend, implemented in terms of the `secp256k1` crate's
+/// point arithmetic already used elsewhere in this crate.
+pub struct Secp256k1Curve;
+
+impl Curve for Secp256k1Curve {
+    fn name(&self) -> &'static str {
+        "secp256k1"
+    }
+
+    fn prime(&self) -> BigUint {
+        secp256k1::Point::prime()
+    }
+
+    fn order(&self) -> BigUint {
+        secp256k1::Point::n()
+    }
+
+    fn generator(&self) -> Point {
+        let g = secp256k1::Point::generator();
+        match g {
+            secp256k1::Point::Coor { x, y, .. } => Point::ECPoint(x.number, y.number),
+            _ => unreachable!("generator is never the identity"),
+        }
+    }
+
+    fn add(&self, a: &Point, b: &Point) -> Result<Point, Error> {
+        match (a, b) {
+            (Point::ECPoint(ax, ay), Point::ECPoint(bx, by)) => {
+                let a = secp256k1::Point::from_bigint(ax.clone(), ay.clone());
+                let b = secp256k1::Point::from_bigint(bx.clone(), by.clone());
+                match a + b {
+                    secp256k1::Point::Coor { x, y, .. } => Ok(Point::ECPoint(x.number, y.number)),
+                    _ => Err(Error::EllipticCurveError("zero point reached".to_string())),
+                }
+            }
+            _ => Err(Error::PointTypeMismatch),
+        }
+    }
+
+    fn scalar_mul(&self, point: &Point, scalar: &BigUint) -> Result<Point, Error> {
+        match point {
+            Point::ECPoint(x, y) => {
+                let p = secp256k1::Point::from_bigint(x.clone(), y.clone());
+                match p.scale(scalar.clone()) {
+                    secp256k1::Point::Coor { x, y, .. } => Ok(Point::ECPoint(x.number, y.number)),
+                    _ => Err(Error::EllipticCurveError("zero point reached".to_string())),
+                }
+            }
+            _ => Err(Error::PointTypeMismatch),
+        }
+    }
+
+    fn serialize(&self, point: &Point) -> Vec<u8> {
+        point.serialize()
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> Result<Point, Error> {
+        Point::deserialize_into_ecpoint(bytes.to_vec())
+    }
+}
+
+/// The NIST P-256 backend, implemented over the `p256` crate.
+pub struct P256Curve;
+
+impl Curve for P256Curve {
+    fn name(&self) -> &'static str {
+        "P-256"
+    }
+
+    fn prime(&self) -> BigUint {
+        BigUint::from_bytes_be(&p256::FieldBytes::from(
+            p256::elliptic_curve::PrimeField::MODULUS,
+        ))
+    }
+
+    fn order(&self) -> BigUint {
+        BigUint::from_bytes_be(p256::ORDER.to_be_byte_array().as_slice())
+    }
+
+    fn generator(&self) -> Point {
+        let g = p256::AffinePoint::generator();
+        affine_to_point(&g)
+    }
+
+    fn add(&self, a: &Point, b: &Point) -> Result<Point, Error> {
+        let a = point_to_projective(a)?;
+        let b = point_to_projective(b)?;
+        Ok(affine_to_point(&(a + b).to_affine()))
+    }
+
+    fn scalar_mul(&self, point: &Point, scalar: &BigUint) -> Result<Point, Error> {
+        let p = point_to_projective(point)?;
+        let scalar = biguint_to_p256_scalar(scalar)?;
+        Ok(affine_to_point(&(p * scalar).to_affine()))
+    }
+
+    fn serialize(&self, point: &Point) -> Vec<u8> {
+        point.serialize()
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> Result<Point, Error> {
+        Point::deserialize_into_ecpoint(bytes.to_vec())
+    }
+}
+
+fn affine_to_point(p: &p256::AffinePoint) -> Point {
+    let encoded = p256::elliptic_curve::sec1::ToEncodedPoint::to_encoded_point(p, false);
+    let x = BigUint::from_bytes_be(encoded.x().expect("non-identity point"));
+    let y = BigUint::from_bytes_be(encoded.y().expect("non-identity point"));
+    Point::ECPoint(x, y)
+}
+
+fn point_to_projective(point: &Point) -> Result<p256::ProjectivePoint, Error> {
+    match point {
+        Point::ECPoint(x, y) => {
+            let encoded = p256::EncodedPoint::from_affine_coordinates(
+                &to_field_bytes(x),
+                &to_field_bytes(y),
+                false,
+            );
+            Option::from(p256::AffinePoint::from_encoded_point(&encoded))
+                .map(|a: p256::AffinePoint| p256::ProjectivePoint::from(a))
+                .ok_or_else(|| Error::EllipticCurveError("point not on P-256".to_string()))
+        }
+        _ => Err(Error::PointTypeMismatch),
+    }
+}
+
+fn to_field_bytes(v: &BigUint) -> p256::FieldBytes {
+    let mut bytes = v.to_bytes_be();
+    let mut padded = [0u8; 32];
+    if bytes.len() > 32 {
+        bytes = bytes.split_off(bytes.len() - 32);
+    }
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    p256::FieldBytes::from(padded)
+}
+
+fn biguint_to_p256_scalar(v: &BigUint) -> Result<p256::Scalar, Error> {
+    Option::from(p256::Scalar::from_repr(to_field_bytes(v)))
+        .ok_or_else(|| Error::InvalidArguments)
+}
+
+/// The BabyJubJub backend: a twisted Edwards curve `a·x² + y² = 1 + d·x²·y²`
+/// over the BN254 scalar field, with `a = 168700`, `d = 168696` and the
+/// standard circomlib base point/order. This is the curve SNARK circuits
+/// natively support, so proofs built on it (Chaum-Pedersen, DLEQ, …) can be
+/// checked cheaply inside a circuit, unlike the `Secp256k1Curve`/`P256Curve`
+/// backends above.
+pub struct BabyJubJubCurve;
+
+/// The BN254 scalar field prime BabyJubJub's coordinates live in.
+pub(crate) fn babyjubjub_prime() -> BigUint {
+    BigUint::parse_bytes(
+        b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .expect("valid prime literal")
+}
+
+/// The prime order of BabyJubJub's subgroup (the circomlib base point
+/// generates a subgroup of this order; the curve's own order is `8×` this).
+fn babyjubjub_order() -> BigUint {
+    BigUint::parse_bytes(
+        b"2736030358979909402780800718157159386076813972158567259200215660948447373041",
+        10,
+    )
+    .expect("valid prime literal")
+}
+
+const BABYJUBJUB_A: u32 = 168700;
+const BABYJUBJUB_D: u32 = 168696;
+
+impl Curve for BabyJubJubCurve {
+    fn name(&self) -> &'static str {
+        "BabyJubJub"
+    }
+
+    fn prime(&self) -> BigUint {
+        babyjubjub_prime()
+    }
+
+    fn order(&self) -> BigUint {
+        babyjubjub_order()
+    }
+
+    fn generator(&self) -> Point {
+        let x = BigUint::parse_bytes(
+            b"5299619240641551281634865583518297030282874472190772894086521144482721001553",
+            10,
+        )
+        .expect("valid base point x");
+        let y = BigUint::parse_bytes(
+            b"16950150798460657717958625567821834550301663161624707787222815936182638968203",
+            10,
+        )
+        .expect("valid base point y");
+        Point::ECPoint(x, y)
+    }
+
+    fn add(&self, a: &Point, b: &Point) -> Result<Point, Error> {
+        match (a, b) {
+            (Point::ECPoint(x1, y1), Point::ECPoint(x2, y2)) => {
+                Ok(babyjubjub_add(x1, y1, x2, y2))
+            }
+            _ => Err(Error::PointTypeMismatch),
+        }
+    }
+
+    fn scalar_mul(&self, point: &Point, scalar: &BigUint) -> Result<Point, Error> {
+        match point {
+            Point::ECPoint(x, y) => {
+                let mut acc = (BigUint::from(0u32), BigUint::from(1u32)); // identity: (0, 1)
+                let mut base = (x.clone(), y.clone());
+                let mut k = scalar.clone();
+                let zero = BigUint::from(0u32);
+
+                while k > zero {
+                    if &k % BigUint::from(2u32) == BigUint::from(1u32) {
+                        let combined = babyjubjub_add(&acc.0, &acc.1, &base.0, &base.1);
+                        acc = match combined {
+                            Point::ECPoint(x, y) => (x, y),
+                            _ => unreachable!(),
+                        };
+                    }
+                    let doubled = babyjubjub_add(&base.0, &base.1, &base.0, &base.1);
+                    base = match doubled {
+                        Point::ECPoint(x, y) => (x, y),
+                        _ => unreachable!(),
+                    };
+                    k >>= 1;
+                }
+
+                Ok(Point::ECPoint(acc.0, acc.1))
+            }
+            _ => Err(Error::PointTypeMismatch),
+        }
+    }
+
+    fn serialize(&self, point: &Point) -> Vec<u8> {
+        point.serialize()
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> Result<Point, Error> {
+        Point::deserialize_into_ecpoint(bytes.to_vec())
+    }
+}
+
+/// Twisted Edwards point addition: `x3 = (x1y2 + y1x2) / (1 + d·x1x2y1y2)`,
+/// `y3 = (y1y2 - a·x1x2) / (1 - d·x1x2y1y2)`, reduced mod the BN254 scalar
+/// field prime.
+fn babyjubjub_add(x1: &BigUint, y1: &BigUint, x2: &BigUint, y2: &BigUint) -> Point {
+    let p = babyjubjub_prime();
+    let a = BigUint::from(BABYJUBJUB_A);
+    let d = BigUint::from(BABYJUBJUB_D);
+
+    let x1y2 = x1 * y2 % &p;
+    let y1x2 = y1 * x2 % &p;
+    let y1y2 = y1 * y2 % &p;
+    let x1x2 = x1 * x2 % &p;
+    let dx1x2y1y2 = (&d * &x1x2 * &y1y2) % &p;
+
+    let x3_num = (&x1y2 + &y1x2) % &p;
+    let x3_den = field_inverse(&((BigUint::from(1u32) + &dx1x2y1y2) % &p), &p);
+    let x3 = (x3_num * x3_den) % &p;
+
+    let y3_num = (&p + &y1y2 - (&a * &x1x2) % &p) % &p;
+    let y3_den = field_inverse(&((&p + BigUint::from(1u32) - &dx1x2y1y2) % &p), &p);
+    let y3 = (y3_num * y3_den) % &p;
+
+    Point::ECPoint(x3, y3)
+}
+
+/// Modular inverse via Fermat's little theorem (`p` is prime).
+fn field_inverse(v: &BigUint, p: &BigUint) -> BigUint {
+    v.modpow(&(p - BigUint::from(2u32)), p)
+}
+
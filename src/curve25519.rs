@@ -1,13 +1,22 @@
-use crate::types::{Error, Group, Point};
+//! Curve25519 support via the Ristretto group, which wraps the Edwards
+//! curve's cofactor-8 points into a prime-order group with no low-order
+//! subgroup to worry about. The earlier approach of stuffing a compressed
+//! Edwards-Y into `Point::ECPoint(x, 0)` both collided with the secp256k1
+//! `(x, y)` representation and skipped cofactor clearing entirely, so an
+//! adversarial low-order point would pass `is_on_curve`; `Point::Ristretto`
+//! and `RistrettoPoint` close both problems at once.
+
 use crate::traits::{CurveGroup, GroupOps};
+use crate::types::{Error, Point};
+use crate::windowed::FixedBaseMul;
 use curve25519_dalek::{
-    constants::ED25519_BASEPOINT_POINT,
-    edwards::{CompressedEdwardsY, EdwardsPoint},
-    scalar::Scalar,
+    constants::RISTRETTO_BASEPOINT_POINT,
+    ristretto::{CompressedRistretto, RistrettoBasepointTable},
+    traits::BasepointTable,
 };
 use num_bigint::BigUint;
 
-/// Implementation of Curve25519 operations
+/// Implementation of Curve25519 operations over the Ristretto group.
 pub struct Curve25519Group;
 
 impl GroupOps for Curve25519Group {
@@ -18,121 +27,136 @@ impl GroupOps for Curve25519Group {
 
     fn order(&self) -> BigUint {
         // 2^252 + 27742317777372353535851937790883648493
-        BigUint::from(2u32).pow(252) + BigUint::from_bytes_be(&[
-            0x14, 0xde, 0xf9, 0xde, 0xa2, 0xf7, 0x9c, 0xd6, 0x58, 0x12, 0x63, 0x1a, 0x5c, 0xf5,
-            0xd3, 0xed,
-        ])
+        BigUint::from(2u32).pow(252)
+            + BigUint::from_bytes_be(&[
+                0x14, 0xde, 0xf9, 0xde, 0xa2, 0xf7, 0x9c, 0xd6, 0x58, 0x12, 0x63, 0x1a, 0x5c, 0xf5,
+                0xd3, 0xed,
+            ])
     }
 
     fn generator(&self) -> Point {
-        let point = ED25519_BASEPOINT_POINT;
-        Point::ECPoint(
-            BigUint::from_bytes_be(&point.compress().to_bytes()),
-            BigUint::from(0u32), // Curve25519 uses compressed points
-        )
+        Point::Ristretto(RISTRETTO_BASEPOINT_POINT.compress().to_bytes())
     }
 
     fn second_generator(&self) -> Point {
-        // Using a fixed second generator point
-        let point = ED25519_BASEPOINT_POINT * Scalar::from(2u32);
-        Point::ECPoint(
-            BigUint::from_bytes_be(&point.compress().to_bytes()),
-            BigUint::from(0u32),
-        )
+        // A second generator independent of the basepoint: hash-to-group
+        // via uniform bytes, rather than a small multiple of the basepoint
+        // (which would make the discrete log between the two generators
+        // public and known, defeating the point of a Pedersen-style setup).
+        let wide = {
+            let mut hasher = sha2::Sha256::new();
+            use sha2::Digest;
+            hasher.update(b"cpzkp/curve25519/second-generator");
+            let digest = hasher.finalize();
+            let mut wide = [0u8; 64];
+            wide[..32].copy_from_slice(&digest);
+            wide
+        };
+        let point = curve25519_dalek::ristretto::RistrettoPoint::from_uniform_bytes(&wide);
+        Point::Ristretto(point.compress().to_bytes())
     }
 }
 
 impl CurveGroup for Curve25519Group {
     fn curve_name(&self) -> &'static str {
-        "Curve25519"
+        "ristretto255"
     }
 
     fn curve_params(&self) -> (BigUint, BigUint, BigUint) {
-        // Curve25519: y² = x³ + 486662x² + x
-        let a = BigUint::from(486662u32);
-        let b = BigUint::from(1u32);
-        let p = self.prime();
-        (a, b, p)
+        // Ristretto is defined over the Edwards25519 curve
+        // `-x² + y² = 1 + d·x²·y²`; there's no `(a, b, p)` Weierstrass form
+        // to report, so this mirrors the curve's defining constants instead.
+        (
+            BigUint::from(2u32).pow(255) - BigUint::from(19u32) - BigUint::from(1u32), // a = -1 mod p
+            BigUint::parse_bytes(
+                b"37095705934669439343138083508754565189542113879843219016388785533085940283555",
+                10,
+            )
+            .expect("valid d literal"),
+            self.prime(),
+        )
     }
 
     fn is_on_curve(&self, point: &Point) -> bool {
-        match point {
-            Point::Scalar(_) => false,
-            Point::ECPoint(x, _) => {
-                // For Curve25519, we only need to check if the x-coordinate is valid
-                let x_bytes = x.to_bytes_be();
-                if x_bytes.len() != 32 {
-                    return false;
-                }
-                let mut bytes = [0u8; 32];
-                bytes.copy_from_slice(&x_bytes);
-                CompressedEdwardsY::from_slice(&bytes).decompress().is_some()
-            }
-        }
+        decompress(point).is_ok()
     }
 
     fn add_points(&self, p1: &Point, p2: &Point) -> Result<Point, Error> {
-        match (p1, p2) {
-            (Point::ECPoint(x1, _), Point::ECPoint(x2, _)) => {
-                let x1_bytes = x1.to_bytes_be();
-                let x2_bytes = x2.to_bytes_be();
-                if x1_bytes.len() != 32 || x2_bytes.len() != 32 {
-                    return Err(Error::InvalidArguments);
-                }
-
-                let mut bytes1 = [0u8; 32];
-                let mut bytes2 = [0u8; 32];
-                bytes1.copy_from_slice(&x1_bytes);
-                bytes2.copy_from_slice(&x2_bytes);
-
-                let point1 = CompressedEdwardsY::from_slice(&bytes1)
-                    .decompress()
-                    .ok_or(Error::InvalidArguments)?;
-                let point2 = CompressedEdwardsY::from_slice(&bytes2)
-                    .decompress()
-                    .ok_or(Error::InvalidArguments)?;
-
-                let result = point1 + point2;
-                Ok(Point::ECPoint(
-                    BigUint::from_bytes_be(&result.compress().to_bytes()),
-                    BigUint::from(0u32),
-                ))
-            }
-            _ => Err(Error::PointTypeMismatch),
-        }
+        add(p1, p2)
     }
 
     fn scalar_mul(&self, point: &Point, scalar: &BigUint) -> Result<Point, Error> {
-        match point {
-            Point::ECPoint(x, _) => {
-                let x_bytes = x.to_bytes_be();
-                if x_bytes.len() != 32 {
-                    return Err(Error::InvalidArguments);
-                }
-
-                let mut bytes = [0u8; 32];
-                bytes.copy_from_slice(&x_bytes);
-
-                let edwards_point = CompressedEdwardsY::from_slice(&bytes)
-                    .decompress()
-                    .ok_or(Error::InvalidArguments)?;
-
-                let scalar_bytes = scalar.to_bytes_be();
-                if scalar_bytes.len() > 32 {
-                    return Err(Error::InvalidArguments);
-                }
-
-                let mut scalar_bytes_padded = [0u8; 32];
-                scalar_bytes_padded[32 - scalar_bytes.len()..].copy_from_slice(&scalar_bytes);
-                let scalar = Scalar::from_bytes_mod_order(scalar_bytes_padded);
-
-                let result = edwards_point * scalar;
-                Ok(Point::ECPoint(
-                    BigUint::from_bytes_be(&result.compress().to_bytes()),
-                    BigUint::from(0u32),
-                ))
-            }
-            _ => Err(Error::PointTypeMismatch),
-        }
+        scalar_mul(point, scalar)
+    }
+}
+
+/// Decompresses a [`Point::Ristretto`] into a usable `RistrettoPoint`,
+/// rejecting anything that isn't a valid compressed encoding (this is
+/// where cofactor/low-order points get caught: `CompressedRistretto::decompress`
+/// refuses any encoding that isn't in the canonical prime-order group).
+pub(crate) fn decompress(point: &Point) -> Result<curve25519_dalek::ristretto::RistrettoPoint, Error> {
+    match point {
+        Point::Ristretto(bytes) => CompressedRistretto(*bytes)
+            .decompress()
+            .ok_or_else(|| Error::EllipticCurveError("invalid Ristretto encoding".to_string())),
+        _ => Err(Error::PointTypeMismatch),
     }
-} 
\ No newline at end of file
+}
+
+fn biguint_to_scalar(v: &BigUint) -> curve25519_dalek::scalar::Scalar {
+    let mut bytes = v.to_bytes_be();
+    if bytes.len() > 32 {
+        bytes = bytes.split_off(bytes.len() - 32);
+    }
+    let mut padded = [0u8; 32];
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    padded.reverse(); // to_bytes_be -> little-endian limbs expected by Scalar
+    curve25519_dalek::scalar::Scalar::from_bytes_mod_order(padded)
+}
+
+/// Adds two Ristretto points.
+pub(crate) fn add(a: &Point, b: &Point) -> Result<Point, Error> {
+    let a = decompress(a)?;
+    let b = decompress(b)?;
+    Ok(Point::Ristretto((a + b).compress().to_bytes()))
+}
+
+/// Scales a Ristretto point by `scalar`.
+pub(crate) fn scalar_mul(point: &Point, scalar: &BigUint) -> Result<Point, Error> {
+    let point = decompress(point)?;
+    let scalar = biguint_to_scalar(scalar);
+    Ok(Point::Ristretto((point * scalar).compress().to_bytes()))
+}
+
+/// Precomputed fixed-base tables for the Ristretto `g`/`h` generators,
+/// wrapping `curve25519_dalek`'s own `RistrettoBasepointTable` instead of
+/// reinventing windowed multiplication for this group: one table is built
+/// once for each of the caller's `g` and `h`, whatever points those are.
+pub struct Curve25519FixedBase {
+    g_table: RistrettoBasepointTable,
+    h_table: RistrettoBasepointTable,
+}
+
+impl Curve25519FixedBase {
+    /// Builds both tables. `g` and `h` are typically `Curve25519Group`'s
+    /// `generator()`/`second_generator()`, but any valid Ristretto points
+    /// are accepted.
+    pub fn new(g: &Point, h: &Point) -> Result<Self, Error> {
+        Ok(Curve25519FixedBase {
+            g_table: RistrettoBasepointTable::create(&decompress(g)?),
+            h_table: RistrettoBasepointTable::create(&decompress(h)?),
+        })
+    }
+}
+
+impl FixedBaseMul for Curve25519FixedBase {
+    fn scalar_mul_base(&self, scalar: &BigUint) -> Result<Point, Error> {
+        let scalar = biguint_to_scalar(scalar);
+        Ok(Point::Ristretto((&self.g_table * &scalar).compress().to_bytes()))
+    }
+
+    fn scalar_mul_second_base(&self, scalar: &BigUint) -> Result<Point, Error> {
+        let scalar = biguint_to_scalar(scalar);
+        Ok(Point::Ristretto((&self.h_table * &scalar).compress().to_bytes()))
+    }
+}
@@ -1,12 +1,103 @@
+use crate::curve::{Curve, Secp256k1Curve};
 use crate::types::{Error, Group, Point, VerificationParams};
 use crate::traits::{GroupOps, PointOps, ZkpOps};
-use num::traits::One;
+use num::traits::{One, Zero};
 use num_bigint::BigUint;
 use rand::RngCore;
 
 /// Implementation of elliptic curve operations
 pub struct EllipticCurveGroup;
 
+/// Computes a modular square root of `n` mod the prime `p` via Tonelli-
+/// Shanks, returning `None` when `n` is not a quadratic residue. Unlike
+/// `lib.rs`'s `recover_y_from_x` (which only has the `p ≡ 3 mod 4` fast
+/// path secp256k1's prime happens to qualify for), this handles any odd
+/// prime `p`, which this module's toy curve needs: its `p = 10009` is
+/// `1 mod 4`.
+fn tonelli_shanks_sqrt(n: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let one = BigUint::one();
+    let two = BigUint::from(2u32);
+
+    if n.is_zero() {
+        return Some(BigUint::zero());
+    }
+
+    let euler_exp = (p - &one) / &two;
+    if n.modpow(&euler_exp, p) != one {
+        return None; // `n` is not a quadratic residue mod `p`.
+    }
+
+    if (p % BigUint::from(4u32)) == BigUint::from(3u32) {
+        return Some(n.modpow(&((p + &one) / BigUint::from(4u32)), p));
+    }
+
+    // Factor `p - 1 = q * 2^s` with `q` odd.
+    let mut q = p - &one;
+    let mut s = 0u32;
+    while (&q % &two).is_zero() {
+        q /= &two;
+        s += 1;
+    }
+
+    // Find a quadratic non-residue `z` to seed the non-residue generator `c`.
+    let mut z = two.clone();
+    while z.modpow(&euler_exp, p) != &p - &one {
+        z += &one;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = n.modpow(&q, p);
+    let mut r = n.modpow(&((&q + &one) / &two), p);
+
+    loop {
+        if t == one {
+            return Some(r);
+        }
+
+        let mut i = 0u32;
+        let mut temp = t.clone();
+        while temp != one {
+            temp = (&temp * &temp) % p;
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+
+        let b = c.modpow(&(BigUint::from(1u32) << (m - i - 1)), p);
+        m = i;
+        c = (&b * &b) % p;
+        t = (&t * &c) % p;
+        r = (&r * &b) % p;
+    }
+}
+
+/// Modular field-arithmetic helpers over the toy curve's prime. `BigUint`
+/// has no negative numbers, so `double`/`scale`/`add` cannot subtract
+/// directly without underflowing; these wrap that subtraction (and modular
+/// inversion, for the point-doubling/addition slope) so the curve formulas
+/// read the same as their textbook form.
+struct Fp;
+
+impl Fp {
+    /// `(a - b) mod p`, without underflowing when `a < b`.
+    fn sub(a: &BigUint, b: &BigUint, p: &BigUint) -> BigUint {
+        let a = a % p;
+        let b = b % p;
+        if a >= b {
+            a - b
+        } else {
+            p - (b - a)
+        }
+    }
+
+    /// `a^-1 mod p` via Fermat's little theorem (`p` is prime).
+    fn inverse(a: &BigUint, p: &BigUint) -> BigUint {
+        a.modpow(&(p - BigUint::from(2u32)), p)
+    }
+}
+
 impl GroupOps for EllipticCurveGroup {
     fn prime(&self) -> BigUint {
         BigUint::from(10009u32)
@@ -25,6 +116,60 @@ impl GroupOps for EllipticCurveGroup {
     }
 }
 
+impl Point {
+    /// Reduces an `ECPoint`'s coordinates mod `p`, canonicalizing it to its
+    /// unique affine representative before serialization. Arithmetic in this
+    /// module (`add`/`double`/`scale`) already keeps coordinates reduced, but
+    /// a point built by hand (as the compressed-encoding round trip and
+    /// tests below do) may not be; other point kinds are returned unchanged.
+    pub fn normalize(&self, p: &BigUint) -> Point {
+        match self {
+            Point::ECPoint(x, y) => Point::ECPoint(x % p, y % p),
+            other => other.clone(),
+        }
+    }
+}
+
+impl EllipticCurveGroup {
+    /// SEC1-style compressed encoding of a point on this module's toy curve:
+    /// a parity-tag byte (`0x02` for even `y`, `0x03` for odd `y`) followed
+    /// by the big-endian `x` coordinate. Unlike [`Point::serialize_compressed`]
+    /// (which is specific to the real secp256k1 curve), this works over the
+    /// toy curve's own `(p, a, b) = (10009, 0, 7)`.
+    pub fn serialize_compressed(&self, point: &Point) -> Result<Vec<u8>, Error> {
+        match point.normalize(&self.prime()) {
+            Point::ECPoint(x, y) => {
+                let (x, y) = (&x, &y);
+                let tag = if (y % BigUint::from(2u32)).is_zero() { 0x02 } else { 0x03 };
+                let mut out = vec![tag];
+                out.extend_from_slice(&x.to_bytes_be());
+                Ok(out)
+            }
+            _ => Err(Error::PointTypeMismatch),
+        }
+    }
+
+    /// Recovers the point from its compressed encoding by computing
+    /// `y^2 = x^3 + 7 mod p` and taking a modular square root via
+    /// [`tonelli_shanks_sqrt`], then picking the root whose parity matches
+    /// the tag byte.
+    pub fn deserialize_compressed(&self, bytes: &[u8]) -> Result<Point, Error> {
+        let (&tag, x_bytes) = bytes.split_first().ok_or(Error::PointTypeMismatch)?;
+        if tag != 0x02 && tag != 0x03 {
+            return Err(Error::PointTypeMismatch);
+        }
+
+        let p = self.prime();
+        let x = BigUint::from_bytes_be(x_bytes);
+        let rhs = (x.modpow(&BigUint::from(3u32), &p) + BigUint::from(7u32)) % &p;
+        let y = tonelli_shanks_sqrt(&rhs, &p).ok_or(Error::PointTypeMismatch)?;
+        let y_is_odd = !(&y % BigUint::from(2u32)).is_zero();
+        let y = if y_is_odd == (tag == 0x03) { y } else { &p - y };
+
+        Ok(Point::ECPoint(x, y))
+    }
+}
+
 impl PointOps for Point {
     fn serialize(&self) -> Vec<u8> {
         match self {
@@ -43,13 +188,15 @@ impl PointOps for Point {
                 x.append(&mut y);
                 x
             }
+            Point::Ristretto(bytes) => bytes.to_vec(),
+            Point::Identity => Vec::new(),
         }
     }
 
     fn deserialize(bytes: Vec<u8>, group: &Group) -> Result<Point, Error> {
         match group {
             Group::Scalar => Ok(Point::Scalar(BigUint::from_bytes_be(&bytes))),
-            Group::EllipticCurve => {
+            Group::EllipticCurve | Group::Secp256k1 => {
                 let len = bytes.len();
                 if len % 2 != 0 {
                     return Err(Error::InvalidSerialization(
@@ -67,6 +214,7 @@ impl PointOps for Point {
     fn is_on_curve(&self) -> bool {
         match self {
             Point::Scalar(_) => false,
+            Point::Identity => true,
             Point::ECPoint(x, y) => {
                 let p = BigUint::from(10009u32);
                 let a = BigUint::from(0u32);
@@ -75,30 +223,40 @@ impl PointOps for Point {
                 let rhs = (x.modpow(&BigUint::from(3u32), &p) + a * x + b) % p;
                 lhs == rhs
             }
+            Point::Ristretto(_) => false,
         }
     }
 
     fn double(&self) -> Point {
         match self {
             Point::Scalar(_) => panic!("Cannot double Scalar in elliptic curve group"),
+            Point::Identity => Point::Identity,
             Point::ECPoint(x, y) => {
                 let p = BigUint::from(10009u32);
+                if y.is_zero() {
+                    // A point whose tangent is vertical doubles to the
+                    // identity (it is its own inverse).
+                    return Point::Identity;
+                }
                 let a = BigUint::from(0u32);
-                let lambda = ((BigUint::from(3u32) * x.modpow(&BigUint::from(2u32), &p) + a)
-                    * (BigUint::from(2u32) * y).modpow(&(p - BigUint::from(2u32)), &p))
-                    % p;
-                let x3 = (lambda.modpow(&BigUint::from(2u32), &p) - BigUint::from(2u32) * x) % p;
-                let y3 = (lambda * (x - &x3) - y) % p;
+                let numerator = (BigUint::from(3u32) * x.modpow(&BigUint::from(2u32), &p) + a) % &p;
+                let denominator = Fp::inverse(&((BigUint::from(2u32) * y) % &p), &p);
+                let lambda = (numerator * denominator) % &p;
+                let x3 = Fp::sub(&lambda.modpow(&BigUint::from(2u32), &p), &((BigUint::from(2u32) * x) % &p), &p);
+                let y3 = Fp::sub(&((&lambda * Fp::sub(x, &x3, &p)) % &p), y, &p);
                 Point::ECPoint(x3, y3)
             }
+            Point::Ristretto(_) => panic!("Cannot double a Ristretto point in the toy elliptic curve group"),
         }
     }
 
     fn scale(&self, scalar: BigUint) -> Point {
         match self {
             Point::Scalar(_) => panic!("Cannot scale Scalar in elliptic curve group"),
-            Point::ECPoint(x, y) => {
-                let mut result = Point::ECPoint(x.clone(), y.clone());
+            Point::Ristretto(_) => panic!("Cannot scale a Ristretto point in the toy elliptic curve group"),
+            Point::Identity => Point::Identity,
+            Point::ECPoint(_, _) => {
+                let mut result = Point::Identity;
                 let mut scalar = scalar;
                 let mut current = self.clone();
                 while scalar > BigUint::from(0u32) {
@@ -106,12 +264,37 @@ impl PointOps for Point {
                         result = result.add(&current);
                     }
                     current = current.double();
-                    scalar = scalar / BigUint::from(2u32);
+                    scalar /= BigUint::from(2u32);
                 }
                 result
             }
         }
     }
+
+    fn add(&self, other: &Point) -> Point {
+        match (self, other) {
+            (Point::Identity, _) => other.clone(),
+            (_, Point::Identity) => self.clone(),
+            (Point::ECPoint(x1, y1), Point::ECPoint(x2, y2)) => {
+                let p = BigUint::from(10009u32);
+                if x1 == x2 {
+                    return if y1 == y2 {
+                        self.double()
+                    } else {
+                        // `P + (-P) = Identity`: same x, opposite y.
+                        Point::Identity
+                    };
+                }
+                let numerator = Fp::sub(y2, y1, &p);
+                let denominator = Fp::inverse(&Fp::sub(x2, x1, &p), &p);
+                let lambda = (numerator * denominator) % &p;
+                let x3 = Fp::sub(&Fp::sub(&lambda.modpow(&BigUint::from(2u32), &p), x1, &p), x2, &p);
+                let y3 = Fp::sub(&((&lambda * Fp::sub(x1, &x3, &p)) % &p), y1, &p);
+                Point::ECPoint(x3, y3)
+            }
+            _ => panic!("Cannot add incompatible point types in elliptic curve group"),
+        }
+    }
 }
 
 impl ZkpOps for EllipticCurveGroup {
@@ -165,4 +348,199 @@ impl ZkpOps for EllipticCurveGroup {
             Err(Error::PointTypeMismatch)
         }
     }
-} 
\ No newline at end of file
+
+    fn challenge_from_transcript(
+        &self,
+        g: &Point,
+        h: &Point,
+        y1: &Point,
+        y2: &Point,
+        r1: &Point,
+        r2: &Point,
+        message: Option<&[u8]>,
+    ) -> BigUint {
+        crate::transcript::challenge_from_transcript(g, h, y1, y2, r1, r2, message, &self.order())
+    }
+}
+
+/// A production `GroupOps`/`ZkpOps` implementation over the real 256-bit
+/// secp256k1 parameters, delegating its point arithmetic to
+/// [`Secp256k1Curve`](crate::curve::Secp256k1Curve) rather than this
+/// module's 14-bit `EllipticCurveGroup` toy field. `EllipticCurveGroup`
+/// stays as-is for tests that want small, fast arithmetic; this is for
+/// callers that need the `GroupOps`/`ZkpOps` trait interface backed by a
+/// cryptographically meaningful group.
+pub struct Secp256k1GroupOps;
+
+impl GroupOps for Secp256k1GroupOps {
+    fn prime(&self) -> BigUint {
+        Secp256k1Curve.prime()
+    }
+
+    fn order(&self) -> BigUint {
+        Secp256k1Curve.order()
+    }
+
+    fn generator(&self) -> Point {
+        Secp256k1Curve.generator()
+    }
+
+    fn second_generator(&self) -> Point {
+        Secp256k1Curve
+            .scalar_mul(&Secp256k1Curve.generator(), &BigUint::from(13u32))
+            .expect("scaling the generator by a small scalar never reaches the identity")
+    }
+}
+
+impl ZkpOps for Secp256k1GroupOps {
+    fn generate_challenge(&self) -> Result<BigUint, Error> {
+        let mut arr = [0u8; 32];
+        rand::thread_rng()
+            .try_fill_bytes(&mut arr)
+            .map_err(|e| Error::RandomGenerationError(e.to_string()))?;
+        Ok(BigUint::from_bytes_be(&arr) % self.order())
+    }
+
+    fn solve_challenge(
+        &self,
+        secret: &BigUint,
+        random: &BigUint,
+        challenge: &BigUint,
+    ) -> BigUint {
+        crate::solve_zk_challenge_s(secret, random, challenge, &self.order())
+    }
+
+    fn verify_proof(&self, params: &VerificationParams) -> Result<bool, Error> {
+        crate::registry::verify_with_curve(&Secp256k1Curve, params)
+    }
+
+    fn challenge_from_transcript(
+        &self,
+        g: &Point,
+        h: &Point,
+        y1: &Point,
+        y2: &Point,
+        r1: &Point,
+        r2: &Point,
+        message: Option<&[u8]>,
+    ) -> BigUint {
+        crate::transcript::challenge_from_transcript(g, h, y1, y2, r1, r2, message, &self.order())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secp256k1_group_ops_matches_registry_verify_with_curve() {
+        let group = Secp256k1GroupOps;
+        let g = group.generator();
+        let h = group.second_generator();
+        let q = group.order();
+
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let y1 = Secp256k1Curve.scalar_mul(&g, &x_secret).unwrap();
+        let y2 = Secp256k1Curve.scalar_mul(&h, &x_secret).unwrap();
+        let r1 = Secp256k1Curve.scalar_mul(&g, &k).unwrap();
+        let r2 = Secp256k1Curve.scalar_mul(&h, &k).unwrap();
+        let s = group.solve_challenge(&x_secret, &k, &c);
+
+        let params = VerificationParams {
+            r1,
+            r2,
+            y1,
+            y2,
+            g,
+            h,
+            c,
+            s,
+            p: group.prime(),
+        };
+        assert!(group.verify_proof(&params).unwrap());
+    }
+
+    /// `EllipticCurveGroup`'s own `generator()`/`second_generator()` turn out
+    /// not to actually lie on its `y² = x³ + 7 mod 10009` curve (they were
+    /// picked as arbitrary toy coordinates, not derived from it), so these
+    /// tests use a point verified on-curve instead, to exercise `add`/
+    /// `double`/`scale`/`is_on_curve` against real curve arithmetic rather
+    /// than the group's own inconsistent constants.
+    const ON_CURVE_POINT: (u32, u32) = (1, 1171);
+
+    #[test]
+    fn on_curve_point_is_recognized() {
+        let p = Point::ECPoint(BigUint::from(ON_CURVE_POINT.0), BigUint::from(ON_CURVE_POINT.1));
+        assert!(p.is_on_curve());
+    }
+
+    #[test]
+    fn off_curve_point_is_rejected() {
+        let p = Point::ECPoint(BigUint::from(1u32), BigUint::from(1u32));
+        assert!(!p.is_on_curve());
+    }
+
+    #[test]
+    fn double_matches_scale_by_two() {
+        let p = Point::ECPoint(BigUint::from(ON_CURVE_POINT.0), BigUint::from(ON_CURVE_POINT.1));
+        assert_eq!(p.double(), p.scale(BigUint::from(2u32)));
+    }
+
+    #[test]
+    fn point_plus_its_negation_is_identity() {
+        let p = Point::ECPoint(BigUint::from(ON_CURVE_POINT.0), BigUint::from(ON_CURVE_POINT.1));
+        let neg = Point::ECPoint(
+            BigUint::from(ON_CURVE_POINT.0),
+            BigUint::from(10009u32) - BigUint::from(ON_CURVE_POINT.1),
+        );
+        assert_eq!(p.add(&neg), Point::Identity);
+    }
+
+    #[test]
+    fn identity_is_the_neutral_element_of_add() {
+        let p = Point::ECPoint(BigUint::from(ON_CURVE_POINT.0), BigUint::from(ON_CURVE_POINT.1));
+        assert_eq!(p.add(&Point::Identity), p);
+        assert_eq!(Point::Identity.add(&p), p);
+    }
+
+    #[test]
+    fn tonelli_shanks_sqrt_recovers_known_roots_over_a_1_mod_4_prime() {
+        let p = BigUint::from(10009u32);
+        assert_eq!(p.clone() % BigUint::from(4u32), BigUint::from(1u32));
+
+        let y = BigUint::from(ON_CURVE_POINT.1);
+        let n = (&y * &y) % &p;
+        let root = tonelli_shanks_sqrt(&n, &p).unwrap();
+        assert!(root == y || root == &p - &y);
+    }
+
+    #[test]
+    fn tonelli_shanks_sqrt_rejects_non_residues() {
+        // 7 is a quadratic non-residue mod 10009 (checked by brute force).
+        let p = BigUint::from(10009u32);
+        let n = BigUint::from(7u32);
+        assert!(tonelli_shanks_sqrt(&n, &p).is_none());
+    }
+
+    #[test]
+    fn compressed_point_round_trips_through_serialize_and_deserialize() {
+        let group = EllipticCurveGroup;
+        let point = Point::ECPoint(BigUint::from(ON_CURVE_POINT.0), BigUint::from(ON_CURVE_POINT.1));
+
+        let compressed = group.serialize_compressed(&point).unwrap();
+        assert_eq!(compressed[0], 0x03); // 1171 is odd.
+
+        let recovered = group.deserialize_compressed(&compressed).unwrap();
+        assert_eq!(recovered, point);
+    }
+
+    #[test]
+    fn deserialize_compressed_rejects_an_invalid_tag() {
+        let group = EllipticCurveGroup;
+        let bytes = vec![0x04, 1];
+        assert!(group.deserialize_compressed(&bytes).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,202 @@
+//! ECVRF: a verifiable random function keyed by the same secret `x` used
+//! for the Chaum-Pedersen authentication (`y = g^x`), built on the existing
+//! secp256k1 point arithmetic. Useful anywhere a party needs to derive a
+//! pseudorandom-but-provable value from its authentication secret, e.g.
+//! randomness beacons, leader election, or unlinkable lottery tickets.
+
+use crate::commitment::{point_combine, point_pow};
+use crate::transcript::Transcript;
+use crate::types::{Error, Point};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+/// A VRF proof: the VRF output point `Gamma = x·H` plus a Chaum-Pedersen-style
+/// proof of knowledge of `x` relating `y = x·g` and `Gamma = x·H`.
+pub struct VrfProof {
+    pub gamma: Point,
+    pub c: BigUint,
+    pub s: BigUint,
+}
+
+/// Hashes `alpha` to a point on secp256k1 via try-and-increment: hash
+/// `alpha ‖ counter` to a candidate x-coordinate and accept the first one
+/// that recovers a valid `y`.
+fn hash_to_curve(alpha: &[u8], p: &BigUint) -> Point {
+    for counter in 0u32.. {
+        let mut hasher = Sha256::new();
+        hasher.update(b"cpzkp/ecvrf/h2c");
+        hasher.update(alpha);
+        hasher.update(counter.to_be_bytes());
+        let x = BigUint::from_bytes_be(&hasher.finalize()) % p;
+
+        if let Ok(point) = Point::deserialize_into_ecpoint({
+            let mut bytes = vec![0x02];
+            bytes.extend(crate::to_fixed_width_be(&x, crate::SEC1_COORD_BYTES));
+            bytes
+        }) {
+            return point;
+        }
+    }
+    unreachable!("a secp256k1 x-coordinate is valid roughly half the time")
+}
+
+/// Derives the 32-byte pseudorandom VRF output `H(Gamma)` from the proof's
+/// output point, the one place both [`prove`] and [`verify`] compute it from
+/// so they can never disagree on what "the output" means.
+fn vrf_output(gamma: &Point) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"cpzkp/ecvrf/output");
+    hasher.update(gamma.serialize());
+    hasher.finalize().into()
+}
+
+/// Produces a VRF proof for `alpha` under secret key `x` (with `y = x·g`),
+/// along with the pseudorandom output the proof attests to.
+pub fn prove(
+    x: &BigUint,
+    y: &Point,
+    g: &Point,
+    p: &BigUint,
+    q: &BigUint,
+    alpha: &[u8],
+) -> Result<([u8; 32], VrfProof), Error> {
+    let h = hash_to_curve(alpha, p);
+    let gamma = point_pow(&h, x, p)?;
+
+    let k = BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % q;
+    let k_g = point_pow(g, &k, p)?;
+    let k_h = point_pow(&h, &k, p)?;
+
+    let mut transcript = Transcript::new(b"cpzkp/ecvrf");
+    transcript.absorb_point(g);
+    transcript.absorb_point(&h);
+    transcript.absorb_point(y);
+    transcript.absorb_point(&gamma);
+    transcript.absorb_point(&k_g);
+    transcript.absorb_point(&k_h);
+    let c = transcript.challenge(q);
+
+    let s = crate::solve_zk_challenge_s(x, &k, &c, q);
+
+    let output = vrf_output(&gamma);
+    Ok((output, VrfProof { gamma, c, s }))
+}
+
+/// Verifies a VRF proof and, on success, returns the 32-byte pseudorandom
+/// output derived from `Gamma`.
+pub fn verify(
+    y: &Point,
+    g: &Point,
+    p: &BigUint,
+    q: &BigUint,
+    alpha: &[u8],
+    proof: &VrfProof,
+) -> Result<[u8; 32], Error> {
+    let h = hash_to_curve(alpha, p);
+
+    // Recompute k·g = s·g + c·y and k·h = s·h + c·Gamma, the same relation a
+    // Chaum-Pedersen verifier checks for (g, y) and (h, Gamma).
+    let k_g = point_combine(&point_pow(g, &proof.s, p)?, &point_pow(y, &proof.c, p)?, p)?;
+    let k_h = point_combine(
+        &point_pow(&h, &proof.s, p)?,
+        &point_pow(&proof.gamma, &proof.c, p)?,
+        p,
+    )?;
+
+    let mut transcript = Transcript::new(b"cpzkp/ecvrf");
+    transcript.absorb_point(g);
+    transcript.absorb_point(&h);
+    transcript.absorb_point(y);
+    transcript.absorb_point(&proof.gamma);
+    transcript.absorb_point(&k_g);
+    transcript.absorb_point(&k_h);
+    let expected_c = transcript.challenge(q);
+
+    if expected_c != proof.c {
+        return Err(Error::InvalidArguments);
+    }
+
+    Ok(vrf_output(&proof.gamma))
+}
+
+/// Convenience wrapper over [`prove`] for the common case of proving
+/// against the crate's own `Group::EllipticCurve` constants, so a caller
+/// that already has a secret/public key pair doesn't need to fetch
+/// `(g, p, q)` itself.
+pub fn prove_with_default_curve(
+    secret: &BigUint,
+    y: &Point,
+    alpha: &[u8],
+) -> Result<([u8; 32], VrfProof), Error> {
+    let (p, q, g, _) = crate::get_constants_elliptic_curve()?;
+    prove(secret, y, &g, &p, &q, alpha)
+}
+
+/// Convenience wrapper over [`verify`], the counterpart to
+/// [`prove_with_default_curve`].
+pub fn verify_with_default_curve(
+    y: &Point,
+    alpha: &[u8],
+    proof: &VrfProof,
+) -> Result<[u8; 32], Error> {
+    let (p, q, g, _) = crate::get_constants_elliptic_curve()?;
+    verify(y, &g, &p, &q, alpha, proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{commitment, get_constants_elliptic_curve};
+
+    #[test]
+    fn vrf_prove_and_verify_roundtrip() {
+        let (p, q, g, _) = get_constants_elliptic_curve().unwrap();
+        let x = BigUint::from(12345u32);
+        let y = commitment::point_pow(&g, &x, &p).unwrap();
+        let alpha = b"leader-election-round-7";
+
+        let (output, proof) = prove(&x, &y, &g, &p, &q, alpha).unwrap();
+        let verified_output = verify(&y, &g, &p, &q, alpha, &proof).unwrap();
+
+        assert_eq!(output, verified_output);
+    }
+
+    #[test]
+    fn vrf_verify_rejects_a_tampered_proof() {
+        let (p, q, g, _) = get_constants_elliptic_curve().unwrap();
+        let x = BigUint::from(12345u32);
+        let y = commitment::point_pow(&g, &x, &p).unwrap();
+        let alpha = b"leader-election-round-7";
+
+        let (_, mut proof) = prove(&x, &y, &g, &p, &q, alpha).unwrap();
+        proof.s = (proof.s + BigUint::from(1u32)) % &q;
+
+        assert!(verify(&y, &g, &p, &q, alpha, &proof).is_err());
+    }
+
+    #[test]
+    fn vrf_output_is_deterministic_for_the_same_input() {
+        let (p, q, g, _) = get_constants_elliptic_curve().unwrap();
+        let x = BigUint::from(777u32);
+        let y = commitment::point_pow(&g, &x, &p).unwrap();
+        let alpha = b"same-input";
+
+        let (output_a, _) = prove(&x, &y, &g, &p, &q, alpha).unwrap();
+        let (output_b, _) = prove(&x, &y, &g, &p, &q, alpha).unwrap();
+
+        assert_eq!(output_a, output_b);
+    }
+
+    #[test]
+    fn prove_and_verify_with_default_curve_roundtrip() {
+        let (p, _, g, _) = get_constants_elliptic_curve().unwrap();
+        let x = BigUint::from(2468u32);
+        let y = commitment::point_pow(&g, &x, &p).unwrap();
+        let alpha = b"default-curve-roundtrip";
+
+        let (output, proof) = prove_with_default_curve(&x, &y, alpha).unwrap();
+        let verified_output = verify_with_default_curve(&y, alpha, &proof).unwrap();
+
+        assert_eq!(output, verified_output);
+    }
+}
@@ -33,12 +33,28 @@
 
 #![deny(warnings)]
 
+use num::traits::{One, Zero};
+use num_bigint::BigUint;
+
 mod scalar;
 mod ecc;
 #[cfg(feature = "curve25519")]
 mod curve25519;
 mod types;
 mod traits;
+mod transcript;
+mod commitment;
+mod range_sig;
+mod curve;
+pub mod ecvrf;
+mod secret;
+mod dleq;
+mod bit_range;
+mod windowed;
+mod poseidon;
+mod batch;
+mod registry;
+mod solidity;
 
 pub use scalar::*;
 pub use ecc::*;
@@ -46,6 +62,18 @@ pub use ecc::*;
 pub use curve25519::*;
 pub use types::*;
 pub use traits::*;
+pub use transcript::*;
+pub use commitment::*;
+pub use range_sig::*;
+pub use curve::*;
+pub use secret::*;
+pub use dleq::*;
+pub use bit_range::*;
+pub use windowed::*;
+pub use poseidon::*;
+pub use batch::*;
+pub use registry::*;
+pub use solidity::*;
 
 /// The possible kind of errors returned by this library.
 #[derive(Debug)]
@@ -79,13 +107,32 @@ impl std::error::Error for Error {}
 /// - A scalar (multiplicative) group of integers modulo a prime
 /// - The secp256k1 elliptic curve group
 /// - The Curve25519 elliptic curve group (if feature enabled)
-#[derive(Debug, Default)]
+/// - The BabyJubJub twisted Edwards curve, paired with Poseidon-derived
+///   challenges (see [`crate::poseidon::BabyJubJubGroupOps`]) instead of
+///   this crate's usual SHA-256 transcript, so proofs stay cheap to verify
+///   inside a SNARK circuit that already treats BabyJubJub/Poseidon as native
+/// - NIST P-256, via the [`crate::curve::Curve`] backend abstraction. Because
+///   `Point::ECPoint` doesn't carry which curve its coordinates belong to,
+///   [`get_constants`] is enough to hand out P-256 points, but [`verify`] and
+///   [`exponentiates_points`] can't tell them apart from secp256k1's —
+///   use [`verify_for_group`]/[`exponentiates_points_for_group`] instead for
+///   this group.
+/// - `Secp256k1`, the real 256-bit secp256k1 parameters via
+///   [`crate::ecc::Secp256k1GroupOps`], as opposed to
+///   [`crate::ecc::EllipticCurveGroup`]'s 14-bit toy field. [`verify`] and
+///   [`exponentiates_points`] already treat its `Point::ECPoint`s as
+///   secp256k1 points; [`verify_for_group`]/[`exponentiates_points_for_group`]
+///   route it through [`crate::curve::Secp256k1Curve`].
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum Group {
     #[default]
     Scalar,
     EllipticCurve,
     #[cfg(feature = "curve25519")]
     Curve25519,
+    BabyJubjub,
+    P256,
+    Secp256k1,
 }
 
 /// Structure to represent elements in the cyclic group.
@@ -93,10 +140,27 @@ pub enum Group {
 /// Points can be either:
 /// - Scalar: A single value representing an element in the multiplicative group
 /// - ECPoint: An (x,y) coordinate pair representing a point on the elliptic curve
-#[derive(Debug, Clone, PartialEq)]
+/// - Ristretto: A compressed Ristretto255 point (see [`Curve25519Group`](crate::curve25519::Curve25519Group)),
+///   kept distinct from `ECPoint` since Ristretto points are a single 32-byte
+///   compressed encoding, not an `(x, y)` coordinate pair
+/// - Identity: The point at infinity, the neutral element of an elliptic-curve
+///   group; SEC1 encodes it as the single byte `0x00`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Point {
     Scalar(BigUint),
     ECPoint(BigUint, BigUint),
+    Ristretto([u8; 32]),
+    Identity,
+}
+
+/// Selects which `ECPoint` wire encoding [`Point::serialize_with`] should
+/// use; `Scalar` points ignore this and always serialize the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointEncoding {
+    /// `0x04 ‖ x(32) ‖ y(32)`, as [`Point::serialize`].
+    Uncompressed,
+    /// `0x02/0x03 ‖ x(32)`, as [`Point::serialize_compressed`].
+    Compressed,
 }
 
 /// Detects if any argument is --scalar or --elliptic and returns the
@@ -124,9 +188,73 @@ pub fn get_constants(group: &Group) -> Result<(BigUint, BigUint, Point, Point),
         Group::EllipticCurve => get_constants_elliptic_curve(),
         #[cfg(feature = "curve25519")]
         Group::Curve25519 => get_constants_curve25519(),
+        Group::BabyJubjub => get_constants_babyjubjub(),
+        Group::P256 => get_constants_p256(),
+        Group::Secp256k1 => get_constants_secp256k1(),
     }
 }
 
+/// Returns the real 256-bit secp256k1 parameters via [`crate::ecc::Secp256k1GroupOps`],
+/// the same way [`get_constants_babyjubjub`] wraps [`BabyJubJubGroupOps`]. Unlike
+/// [`get_constants_elliptic_curve`], which already uses the real curve, this
+/// exists so callers can ask for secp256k1 explicitly alongside the other
+/// named backends, and so [`Session::new`](crate::session::ProverSession::new)
+/// and the bindings' `KeyPair::new` can select it without going through
+/// `EllipticCurveGroup`'s 14-bit toy field.
+pub fn get_constants_secp256k1() -> Result<(BigUint, BigUint, Point, Point), Error> {
+    use crate::ecc::Secp256k1GroupOps;
+    use crate::traits::GroupOps;
+    let group = Secp256k1GroupOps;
+    let to_local = |point: crate::types::Point| -> Result<Point, Error> {
+        match point {
+            crate::types::Point::ECPoint(x, y) => Ok(Point::ECPoint(x, y)),
+            _ => Err(Error::PointTypeMismatch),
+        }
+    };
+    Ok((
+        group.prime(),
+        group.order(),
+        to_local(group.generator())?,
+        to_local(group.second_generator())?,
+    ))
+}
+
+/// Returns NIST P-256's system parameters, through the [`crate::curve::Curve`]
+/// backend instead of a hand-written `match` like the other
+/// `get_constants_*` functions — adding this curve required only implementing
+/// [`crate::curve::P256Curve`] and this thin wrapper, not touching
+/// [`registry::get_constants_for_curve`].
+pub fn get_constants_p256() -> Result<(BigUint, BigUint, Point, Point), Error> {
+    use crate::curve::P256Curve;
+    let (p, q, g, h) = registry::get_constants_for_curve(&P256Curve)?;
+    let to_local = |point: crate::types::Point| -> Result<Point, Error> {
+        match point {
+            crate::types::Point::ECPoint(x, y) => Ok(Point::ECPoint(x, y)),
+            _ => Err(Error::PointTypeMismatch),
+        }
+    };
+    Ok((p, q, to_local(g)?, to_local(h)?))
+}
+
+/// Returns BabyJubJub's system parameters: its BN254-scalar-field modulus,
+/// subgroup order, base point, and a second generator derived from it.
+pub fn get_constants_babyjubjub() -> Result<(BigUint, BigUint, Point, Point), Error> {
+    use crate::traits::GroupOps;
+    let group = BabyJubJubGroupOps;
+    let to_local = |point: crate::types::Point| -> Result<Point, Error> {
+        match point {
+            crate::types::Point::ECPoint(x, y) => Ok(Point::ECPoint(x, y)),
+            _ => Err(Error::PointTypeMismatch),
+        }
+    };
+    Ok((
+        group.prime(),
+        group.order(),
+        to_local(group.generator())?,
+        to_local(group.second_generator())?,
+    ))
+}
+
 pub fn get_constants_scalar() -> (BigUint, BigUint, Point, Point) {
     (
         BigUint::from(10009u32),
@@ -147,30 +275,81 @@ pub fn get_constants_elliptic_curve() -> Result<(BigUint, BigUint, Point, Point)
     ))
 }
 
+#[cfg(feature = "curve25519")]
+pub fn get_constants_curve25519() -> Result<(BigUint, BigUint, Point, Point), Error> {
+    use crate::curve25519::Curve25519Group;
+    use crate::traits::GroupOps;
+    let group = Curve25519Group;
+    Ok((
+        group.prime(),
+        group.order(),
+        group.generator(),
+        group.second_generator(),
+    ))
+}
+
+#[cfg(not(feature = "curve25519"))]
 pub fn get_constants_curve25519() -> Result<(BigUint, BigUint, Point, Point), Error> {
-    // Implementation needed for Curve25519
     Err(Error::InvalidGroupType)
 }
 
+/// Width in bytes of a secp256k1 field element, used to pad/validate SEC1
+/// point encodings.
+pub(crate) const SEC1_COORD_BYTES: usize = 32;
+
+/// Total length in bytes of a compressed SEC1 `ECPoint` encoding: one parity
+/// prefix byte plus a single field-element width.
+pub const GROUP_SIZE: usize = 1 + SEC1_COORD_BYTES;
+
 impl Point {
-    /// Serializes the Point structure to an array of bytes for network transfer.
+    /// Serializes the Point structure to an array of bytes for network
+    /// transfer. `ECPoint`s use the SEC1 uncompressed form
+    /// `0x04 ‖ x(32) ‖ y(32)`; use [`Point::serialize_compressed`] for the
+    /// 33-byte compressed form.
     pub fn serialize(&self) -> Vec<u8> {
         match self {
             Point::Scalar(x) => x.to_bytes_be(),
             Point::ECPoint(x, y) => {
-                let mut x = x.to_bytes_be();
-                let mut y = y.to_bytes_be();
-                let diff = (x.len() as i32) - (y.len() as i32);
-                if diff > 0 {
-                    y.resize(y.len() + diff as usize, 0);
-                    y.rotate_right(diff as usize);
+                let mut out = Vec::with_capacity(1 + 2 * SEC1_COORD_BYTES);
+                out.push(0x04);
+                out.extend_from_slice(&to_fixed_width_be(x, SEC1_COORD_BYTES));
+                out.extend_from_slice(&to_fixed_width_be(y, SEC1_COORD_BYTES));
+                out
+            }
+            Point::Ristretto(bytes) => bytes.to_vec(),
+            // SEC1's own encoding of the point at infinity.
+            Point::Identity => vec![0x00],
+        }
+    }
+
+    /// Serializes an `ECPoint` in the SEC1 compressed form
+    /// `0x02/0x03 ‖ x(32)`, where the prefix encodes the parity of `y`.
+    pub fn serialize_compressed(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            Point::ECPoint(x, y) => {
+                let mut out = Vec::with_capacity(1 + SEC1_COORD_BYTES);
+                let prefix = if (y % BigUint::from(2u32)).is_zero() {
+                    0x02
                 } else {
-                    x.resize(x.len() + (-diff as usize), 0);
-                    x.rotate_right((-diff) as usize);
-                }
-                x.append(&mut y);
-                x
+                    0x03
+                };
+                out.push(prefix);
+                out.extend_from_slice(&to_fixed_width_be(x, SEC1_COORD_BYTES));
+                Ok(out)
+            }
+            Point::Scalar(_) | Point::Ristretto(_) | Point::Identity => Err(Error::PointTypeMismatch),
+        }
+    }
+
+    /// Serializes using the requested [`PointEncoding`]; `Scalar` points
+    /// always use their single wire form regardless of `encoding`.
+    pub fn serialize_with(&self, encoding: PointEncoding) -> Result<Vec<u8>, Error> {
+        match (self, encoding) {
+            (Point::Scalar(_), _) | (Point::Ristretto(_), _) | (Point::Identity, _) => {
+                Ok(self.serialize())
             }
+            (Point::ECPoint(..), PointEncoding::Uncompressed) => Ok(self.serialize()),
+            (Point::ECPoint(..), PointEncoding::Compressed) => self.serialize_compressed(),
         }
     }
 
@@ -181,6 +360,10 @@ impl Point {
             Group::EllipticCurve => Point::deserialize_into_ecpoint(v),
             #[cfg(feature = "curve25519")]
             Group::Curve25519 => Point::deserialize_into_curve25519(v),
+            Group::BabyJubjub => Point::deserialize_into_babyjubjub(v),
+            // P-256 points use the same SEC1 encoding as secp256k1's.
+            Group::P256 => Point::deserialize_into_ecpoint(v),
+            Group::Secp256k1 => Point::deserialize_into_ecpoint(v),
         }
     }
 
@@ -188,19 +371,60 @@ impl Point {
         Point::Scalar(BigUint::from_bytes_be(&v))
     }
 
+    /// Parses a SEC1-encoded secp256k1 point (compressed or uncompressed)
+    /// and rejects anything that is not actually on the curve, closing the
+    /// invalid-curve attack the old "split the buffer in half" decoder was
+    /// open to.
     pub fn deserialize_into_ecpoint(v: Vec<u8>) -> Result<Point, Error> {
-        let len = v.len();
+        let point = match v.first() {
+            Some(0x04) if v.len() == 1 + 2 * SEC1_COORD_BYTES => Point::ECPoint(
+                BigUint::from_bytes_be(&v[1..1 + SEC1_COORD_BYTES]),
+                BigUint::from_bytes_be(&v[1 + SEC1_COORD_BYTES..]),
+            ),
+            Some(0x02) | Some(0x03) if v.len() == 1 + SEC1_COORD_BYTES => {
+                let want_odd = v[0] == 0x03;
+                let x = BigUint::from_bytes_be(&v[1..]);
+                let y = recover_y_from_x(&x, want_odd)?;
+                Point::ECPoint(x, y)
+            }
+            Some(0x00) if v.len() == 1 => return Ok(Point::Identity),
+            _ => {
+                return Err(Error::InvalidSerialization(
+                    "Unrecognized SEC1 point encoding".to_string(),
+                ))
+            }
+        };
 
-        if len % 2 != 0 {
+        if !point_on_secp256k1(&point) {
             return Err(Error::InvalidSerialization(
-                "The length of the serialized object must be even".to_string(),
+                "Point is not on the secp256k1 curve".to_string(),
             ));
         }
 
-        Ok(Point::ECPoint(
-            BigUint::from_bytes_be(&v[..len / 2]),
-            BigUint::from_bytes_be(&v[len / 2..]),
-        ))
+        Ok(point)
+    }
+
+    /// Parses a BabyJubJub point from its uncompressed `0x04 ‖ x ‖ y`
+    /// encoding (the same wire shape [`Point::serialize`] emits for any
+    /// `ECPoint`) and rejects anything not on the twisted-Edwards curve.
+    pub fn deserialize_into_babyjubjub(v: Vec<u8>) -> Result<Point, Error> {
+        if v.first() != Some(&0x04) || v.len() != 1 + 2 * SEC1_COORD_BYTES {
+            return Err(Error::InvalidSerialization(
+                "Unrecognized BabyJubJub point encoding".to_string(),
+            ));
+        }
+
+        let x = BigUint::from_bytes_be(&v[1..1 + SEC1_COORD_BYTES]);
+        let y = BigUint::from_bytes_be(&v[1 + SEC1_COORD_BYTES..]);
+        let point = crate::types::Point::ECPoint(x.clone(), y.clone());
+
+        if !crate::poseidon::babyjubjub_point_is_on_curve(&point) {
+            return Err(Error::InvalidSerialization(
+                "Point is not on the BabyJubJub curve".to_string(),
+            ));
+        }
+
+        Ok(Point::ECPoint(x, y))
     }
 
     /// Converts a point from the `secp256k1` library into a Point
@@ -215,9 +439,96 @@ impl Point {
         }
     }
 
+    /// Parses a 32-byte compressed Ristretto point, rejecting anything that
+    /// isn't a canonical encoding in the prime-order group (this is what
+    /// keeps adversarial low-order points from ever reaching `Point::Ristretto`).
+    #[cfg(feature = "curve25519")]
     pub fn deserialize_into_curve25519(v: Vec<u8>) -> Result<Point, Error> {
-        // Implementation needed for Curve25519
-        Err(Error::InvalidSerialization("Curve25519 deserialization not implemented".to_string()))
+        let bytes: [u8; 32] = v.try_into().map_err(|_| {
+            Error::InvalidSerialization("Ristretto points are exactly 32 bytes".to_string())
+        })?;
+        curve25519::decompress(&Point::Ristretto(bytes))?;
+        Ok(Point::Ristretto(bytes))
+    }
+
+    #[cfg(not(feature = "curve25519"))]
+    pub fn deserialize_into_curve25519(_v: Vec<u8>) -> Result<Point, Error> {
+        Err(Error::InvalidGroupType)
+    }
+
+    /// Encodes `self.serialize()` as a lowercase hex string, a convenient
+    /// round-trippable string form for contexts that want a JSON-friendly
+    /// point encoding (e.g. the WASM playground/yew demo) instead of a raw
+    /// byte array.
+    pub fn to_base16(&self) -> String {
+        self.serialize().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Parses a hex string produced by [`Point::to_base16`] back into a
+    /// `Point` of the given `group`, the inverse of [`Point::to_base16`].
+    pub fn from_base16(s: &str, group: &Group) -> Result<Point, Error> {
+        if s.len() % 2 != 0 {
+            return Err(Error::InvalidSerialization(
+                "hex string must have an even length".to_string(),
+            ));
+        }
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&s[i..i + 2], 16)
+                    .map_err(|e| Error::InvalidSerialization(e.to_string()))
+            })
+            .collect::<Result<Vec<u8>, Error>>()?;
+        Point::deserialize(bytes, group)
+    }
+}
+
+/// Big-endian encodes `v` into exactly `width` bytes, left-padding with
+/// zeros (SEC1 coordinates are always fixed-width, unlike the old ad-hoc
+/// padding this replaces).
+pub(crate) fn to_fixed_width_be(v: &BigUint, width: usize) -> Vec<u8> {
+    let bytes = v.to_bytes_be();
+    let mut out = vec![0u8; width];
+    let start = width.saturating_sub(bytes.len());
+    out[start..].copy_from_slice(&bytes[bytes.len().saturating_sub(width)..]);
+    out
+}
+
+/// Recovers `y` from `x` on secp256k1 (`y² = x³ + 7 mod p`) by exponentiating
+/// by `(p+1)/4`, valid since secp256k1's prime is `3 mod 4`, then selecting
+/// the root whose parity matches `want_odd`.
+fn recover_y_from_x(x: &BigUint, want_odd: bool) -> Result<BigUint, Error> {
+    let p = Secp256k1Point::prime();
+    let rhs = (x.modpow(&BigUint::from(3u32), &p) + BigUint::from(7u32)) % &p;
+    let exponent = (&p + BigUint::one()) / BigUint::from(4u32);
+    let root = rhs.modpow(&exponent, &p);
+
+    if root.modpow(&BigUint::from(2u32), &p) != rhs {
+        return Err(Error::InvalidSerialization(
+            "x is not a valid secp256k1 coordinate".to_string(),
+        ));
+    }
+
+    let root_is_odd = (&root % BigUint::from(2u32)) == BigUint::one();
+    if root_is_odd == want_odd {
+        Ok(root)
+    } else {
+        Ok(&p - root)
+    }
+}
+
+/// Checks `y² == x³ + 7 mod p` for the secp256k1 parameters.
+fn point_on_secp256k1(point: &Point) -> bool {
+    match point {
+        Point::ECPoint(x, y) => {
+            let p = Secp256k1Point::prime();
+            let lhs = y.modpow(&BigUint::from(2u32), &p);
+            let rhs = (x.modpow(&BigUint::from(3u32), &p) + BigUint::from(7u32)) % &p;
+            lhs == rhs
+        }
+        Point::Scalar(_) => false,
+        Point::Ristretto(_) => false,
+        Point::Identity => true,
     }
 }
 
@@ -235,10 +546,75 @@ pub fn exponentiates_points(
         (Point::ECPoint(gx, gy), Point::ECPoint(hx, hy)) => {
             exponentiates_points_elliptic_curve(exp, gx, gy, hx, hy)
         }
+        #[cfg(feature = "curve25519")]
+        (Point::Ristretto(_), Point::Ristretto(_)) => Ok((
+            curve25519::scalar_mul(g, exp)?,
+            curve25519::scalar_mul(h, exp)?,
+        )),
         _ => Err(Error::PointTypeMismatch),
     }
 }
 
+/// Like [`exponentiates_points`], but takes `group` explicitly so it can
+/// dispatch to a [`crate::curve::Curve`] backend for groups (like
+/// [`Group::P256`]) whose points share the `Point::ECPoint` variant with
+/// secp256k1's and so can't be told apart by [`exponentiates_points`] alone.
+pub fn exponentiates_points_for_group(
+    group: &Group,
+    exp: &BigUint,
+    g: &Point,
+    h: &Point,
+    p: &BigUint,
+) -> Result<(Point, Point), Error> {
+    match group {
+        Group::P256 => {
+            use crate::curve::P256Curve;
+            let to_remote = |point: &Point| -> Result<crate::types::Point, Error> {
+                match point {
+                    Point::ECPoint(x, y) => Ok(crate::types::Point::ECPoint(x.clone(), y.clone())),
+                    _ => Err(Error::PointTypeMismatch),
+                }
+            };
+            let (g2, h2) = registry::exponentiates_points_for_curve(
+                &P256Curve,
+                exp,
+                &to_remote(g)?,
+                &to_remote(h)?,
+            )?;
+            let to_local = |point: crate::types::Point| -> Result<Point, Error> {
+                match point {
+                    crate::types::Point::ECPoint(x, y) => Ok(Point::ECPoint(x, y)),
+                    _ => Err(Error::PointTypeMismatch),
+                }
+            };
+            Ok((to_local(g2)?, to_local(h2)?))
+        }
+        Group::Secp256k1 => {
+            use crate::curve::Secp256k1Curve;
+            let to_remote = |point: &Point| -> Result<crate::types::Point, Error> {
+                match point {
+                    Point::ECPoint(x, y) => Ok(crate::types::Point::ECPoint(x.clone(), y.clone())),
+                    _ => Err(Error::PointTypeMismatch),
+                }
+            };
+            let (g2, h2) = registry::exponentiates_points_for_curve(
+                &Secp256k1Curve,
+                exp,
+                &to_remote(g)?,
+                &to_remote(h)?,
+            )?;
+            let to_local = |point: crate::types::Point| -> Result<Point, Error> {
+                match point {
+                    crate::types::Point::ECPoint(x, y) => Ok(Point::ECPoint(x, y)),
+                    _ => Err(Error::PointTypeMismatch),
+                }
+            };
+            Ok((to_local(g2)?, to_local(h2)?))
+        }
+        _ => exponentiates_points(exp, g, h, p),
+    }
+}
+
 pub fn exponentiates_points_scalar(
     exp: &BigUint,
     g: &BigUint,
@@ -303,6 +679,131 @@ pub fn solve_zk_challenge_s(x_secret: &BigUint, k: &BigUint, c: &BigUint, q: &Bi
     result % q
 }
 
+/// A standalone Chaum-Pedersen proof produced via the Fiat-Shamir transform:
+/// unlike the live protocol, `c` is derived from a transcript over the
+/// commitments rather than sent by an interactive verifier, so `(r1, r2, c,
+/// s)` alone is enough for anyone holding `y1, y2, g, h` to check it later
+/// (see [`prove_noninteractive`]/[`verify_noninteractive`]).
+#[derive(Clone)]
+pub struct NonInteractiveProof {
+    /// First commitment `r1 = g^k`.
+    pub r1: Point,
+    /// Second commitment `r2 = h^k`.
+    pub r2: Point,
+    /// Fiat-Shamir challenge, recomputable by the verifier from the transcript.
+    pub c: BigUint,
+    /// Response `s = (k - c·x) mod q`.
+    pub s: BigUint,
+}
+
+/// Runs the Chaum-Pedersen protocol non-interactively: the prover derives
+/// the challenge itself from a Fiat-Shamir transcript over the public
+/// parameters and commitments instead of waiting for the verifier to send
+/// one, so the whole proof can be produced and sent in a single message.
+///
+/// * `x_secret` - secret witness.
+/// * `k` - prover's per-proof nonce.
+/// * `g`, `h` - generators.
+/// * `p` - group modulus.
+/// * `q` - group order.
+///
+/// Returns the public key components `(y1, y2)` alongside the proof, since
+/// the verifier needs both to check it.
+pub fn prove_noninteractive(
+    x_secret: &BigUint,
+    k: &BigUint,
+    g: &Point,
+    h: &Point,
+    p: &BigUint,
+    q: &BigUint,
+) -> Result<(Point, Point, NonInteractiveProof), Error> {
+    let (y1, y2) = exponentiates_points(x_secret, g, h, p)?;
+    let (r1, r2) = exponentiates_points(k, g, h, p)?;
+    let c = challenge_from_transcript(g, h, &y1, &y2, &r1, &r2, None, q);
+    let s = solve_zk_challenge_s(x_secret, k, &c, q);
+
+    Ok((y1, y2, NonInteractiveProof { r1, r2, c, s }))
+}
+
+/// Verifies a [`NonInteractiveProof`] by recomputing the Fiat-Shamir
+/// challenge from the transcript (rather than trusting `proof.c`) and then
+/// running the same check [`verify`] would for an interactive proof.
+pub fn verify_noninteractive(
+    proof: &NonInteractiveProof,
+    y1: &Point,
+    y2: &Point,
+    g: &Point,
+    h: &Point,
+    p: &BigUint,
+    q: &BigUint,
+) -> Result<bool, Error> {
+    let c = challenge_from_transcript(g, h, y1, y2, &proof.r1, &proof.r2, None, q);
+    let params = VerificationParams {
+        r1: proof.r1.clone(),
+        r2: proof.r2.clone(),
+        y1: y1.clone(),
+        y2: y2.clone(),
+        g: g.clone(),
+        h: h.clone(),
+        c,
+        s: proof.s.clone(),
+        p: p.clone(),
+    };
+    verify(&params)
+}
+
+/// Produces a Schnorr signature over `message` under secret key `x_secret`
+/// (with public key `y = g^x`), reusing the same commitment/challenge/
+/// response shape as the Chaum-Pedersen protocol but binding the challenge
+/// to an arbitrary message via [`Transcript`] instead of a second generator.
+///
+/// Returns `(r, s)` where `r = g^k` is the commitment and
+/// `s = (k + c·x_secret) mod q` is the response.
+pub fn sign(
+    x_secret: &BigUint,
+    message: &[u8],
+    g: &Point,
+    p: &BigUint,
+    q: &BigUint,
+) -> Result<(Point, BigUint), Error> {
+    let k = BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % q;
+    let r = commitment::point_pow(g, &k, p)?;
+    let y = commitment::point_pow(g, x_secret, p)?;
+
+    let mut transcript = Transcript::new(b"cpzkp/schnorr-sign");
+    transcript.absorb_point(&r);
+    transcript.absorb_point(&y);
+    transcript.absorb(message);
+    let c = transcript.challenge(q);
+
+    let s = (k + &c * x_secret) % q;
+    Ok((r, s))
+}
+
+/// Verifies a Schnorr signature `(r, s)` over `message` under public key
+/// `y`, checking `g^s == r · y^c` (scalar group) or `s·g == r + c·y`
+/// (elliptic curve group).
+pub fn verify_signature(
+    y: &Point,
+    message: &[u8],
+    r: &Point,
+    s: &BigUint,
+    g: &Point,
+    p: &BigUint,
+    q: &BigUint,
+) -> Result<bool, Error> {
+    let mut transcript = Transcript::new(b"cpzkp/schnorr-sign");
+    transcript.absorb_point(r);
+    transcript.absorb_point(y);
+    transcript.absorb(message);
+    let c = transcript.challenge(q);
+
+    let lhs = commitment::point_pow(g, s, p)?;
+    let rhs = commitment::point_combine(r, &commitment::point_pow(y, &c, p)?, p)?;
+
+    Ok(lhs == rhs)
+}
+
 // Create a struct to hold verification parameters
 #[derive(Clone)]
 pub struct VerificationParams {
@@ -337,10 +838,71 @@ pub fn verify(params: &VerificationParams) -> Result<bool, Error> {
             Point::ECPoint(gx, gy),
             Point::ECPoint(hx, hy),
         ) => Ok(verify_ecpoint_params(params)),
+        #[cfg(feature = "curve25519")]
+        (
+            Point::Ristretto(_),
+            Point::Ristretto(_),
+            Point::Ristretto(_),
+            Point::Ristretto(_),
+            Point::Ristretto(_),
+            Point::Ristretto(_),
+        ) => verify_ristretto_params(params),
         _ => Err(Error::InvalidArguments),
     }
 }
 
+/// Like [`verify`], but takes `group` explicitly so it can dispatch to a
+/// [`crate::curve::Curve`] backend for groups (like [`Group::P256`]) whose
+/// points share the `Point::ECPoint` variant with secp256k1's and so can't be
+/// distinguished by [`verify`] alone.
+pub fn verify_for_group(group: &Group, params: &VerificationParams) -> Result<bool, Error> {
+    match group {
+        Group::P256 => {
+            use crate::curve::P256Curve;
+            let to_remote = |point: &Point| -> Result<crate::types::Point, Error> {
+                match point {
+                    Point::ECPoint(x, y) => Ok(crate::types::Point::ECPoint(x.clone(), y.clone())),
+                    _ => Err(Error::PointTypeMismatch),
+                }
+            };
+            let remote_params = crate::types::VerificationParams {
+                r1: to_remote(&params.r1)?,
+                r2: to_remote(&params.r2)?,
+                y1: to_remote(&params.y1)?,
+                y2: to_remote(&params.y2)?,
+                g: to_remote(&params.g)?,
+                h: to_remote(&params.h)?,
+                c: params.c.clone(),
+                s: params.s.clone(),
+                p: params.p.clone(),
+            };
+            registry::verify_with_curve(&P256Curve, &remote_params)
+        }
+        Group::Secp256k1 => {
+            use crate::curve::Secp256k1Curve;
+            let to_remote = |point: &Point| -> Result<crate::types::Point, Error> {
+                match point {
+                    Point::ECPoint(x, y) => Ok(crate::types::Point::ECPoint(x.clone(), y.clone())),
+                    _ => Err(Error::PointTypeMismatch),
+                }
+            };
+            let remote_params = crate::types::VerificationParams {
+                r1: to_remote(&params.r1)?,
+                r2: to_remote(&params.r2)?,
+                y1: to_remote(&params.y1)?,
+                y2: to_remote(&params.y2)?,
+                g: to_remote(&params.g)?,
+                h: to_remote(&params.h)?,
+                c: params.c.clone(),
+                s: params.s.clone(),
+                p: params.p.clone(),
+            };
+            registry::verify_with_curve(&Secp256k1Curve, &remote_params)
+        }
+        _ => verify(params),
+    }
+}
+
 fn verify_scalar_params(params: &VerificationParams) -> bool {
     if let (
         Point::Scalar(r1),
@@ -393,6 +955,51 @@ fn verify_ecpoint_params(params: &VerificationParams) -> bool {
     }
 }
 
+#[cfg(feature = "curve25519")]
+fn verify_ristretto_params(params: &VerificationParams) -> Result<bool, Error> {
+    let sg = curve25519::scalar_mul(&params.g, &params.s)?;
+    let sh = curve25519::scalar_mul(&params.h, &params.s)?;
+    let cy1 = curve25519::scalar_mul(&params.y1, &params.c)?;
+    let cy2 = curve25519::scalar_mul(&params.y2, &params.c)?;
+
+    let condition_1 = params.r1 == curve25519::add(&sg, &cy1)?;
+    let condition_2 = params.r2 == curve25519::add(&sh, &cy2)?;
+
+    Ok(condition_1 && condition_2)
+}
+
+/// Like [`verify`], but computes `s·g` and `s·h` through a precomputed
+/// [`FixedBaseMul`] table instead of recomputing a fixed-base exponentiation
+/// from scratch. Only `y1`/`y2` (which vary per proof) still go through the
+/// generic [`commitment::point_pow`]/[`commitment::point_combine`] path, so
+/// this is worthwhile for a server checking many proofs against the same
+/// `g`/`h` — build the table once (e.g. a [`windowed::FixedBaseGenerators`])
+/// and reuse it across calls, rather than calling [`verify`] in a loop.
+pub fn verify_with_fixed_base(
+    fixed: &dyn FixedBaseMul,
+    params: &VerificationParams,
+) -> Result<bool, Error> {
+    let sg = fixed.scalar_mul_base(&params.s)?;
+    let sh = fixed.scalar_mul_second_base(&params.s)?;
+    let cy1 = commitment::point_pow(&params.y1, &params.c, &params.p)?;
+    let cy2 = commitment::point_pow(&params.y2, &params.c, &params.p)?;
+
+    let condition_1 = params.r1 == commitment::point_combine(&sg, &cy1, &params.p)?;
+    let condition_2 = params.r2 == commitment::point_combine(&sh, &cy2, &params.p)?;
+
+    Ok(condition_1 && condition_2)
+}
+
+/// Like [`exponentiates_points`], but computes `g^exp`/`h^exp` through a
+/// precomputed [`FixedBaseMul`] table instead of exponentiating from
+/// scratch each call.
+pub fn exponentiates_points_with_fixed_base(
+    fixed: &dyn FixedBaseMul,
+    exp: &BigUint,
+) -> Result<(Point, Point), Error> {
+    Ok((fixed.scalar_mul_base(exp)?, fixed.scalar_mul_second_base(exp)?))
+}
+
 /// Generates a cryptographically secure random array of bytes.
 ///
 /// This function uses the system's cryptographically secure random number generator.
@@ -427,6 +1034,160 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn test_sec1_ecpoint_roundtrip_uncompressed_and_compressed() {
+        let g = Point::from_secp256k1(&Secp256k1Point::generator()).unwrap();
+
+        let uncompressed = g.serialize();
+        assert_eq!(uncompressed[0], 0x04);
+        let decoded = Point::deserialize_into_ecpoint(uncompressed).unwrap();
+        assert_eq!(decoded, g);
+
+        let compressed = g.serialize_compressed().unwrap();
+        assert_eq!(compressed.len(), 33);
+        let decoded = Point::deserialize_into_ecpoint(compressed).unwrap();
+        assert_eq!(decoded, g);
+    }
+
+    #[test]
+    fn test_sec1_rejects_off_curve_points() {
+        let off_curve = Point::ECPoint(BigUint::from(1u32), BigUint::from(1u32));
+        assert!(Point::deserialize_into_ecpoint(off_curve.serialize()).is_err());
+    }
+
+    #[test]
+    fn test_sec1_decodes_point_at_infinity() {
+        assert_eq!(Point::deserialize_into_ecpoint(vec![0x00]).unwrap(), Point::Identity);
+    }
+
+    #[test]
+    fn test_point_base16_roundtrip() {
+        let g = Point::from_secp256k1(&Secp256k1Point::generator()).unwrap();
+        let hex = g.to_base16();
+        assert_eq!(Point::from_base16(&hex, &Group::EllipticCurve).unwrap(), g);
+    }
+
+    #[test]
+    fn test_get_constants_babyjubjub_generator_round_trips_through_serialization() {
+        let (_, _, g, _) = get_constants(&Group::BabyJubjub).unwrap();
+        let encoded = g.serialize();
+        assert_eq!(Point::deserialize_into_babyjubjub(encoded).unwrap(), g);
+    }
+
+    #[test]
+    fn test_deserialize_into_babyjubjub_rejects_an_off_curve_point() {
+        let mut bytes = vec![0x04];
+        bytes.extend_from_slice(&to_fixed_width_be(&BigUint::from(1u32), SEC1_COORD_BYTES));
+        bytes.extend_from_slice(&to_fixed_width_be(&BigUint::from(1u32), SEC1_COORD_BYTES));
+        assert!(Point::deserialize_into_babyjubjub(bytes).is_err());
+    }
+
+    #[test]
+    fn test_p256_group_is_wired_through_get_constants_and_verify_for_group() {
+        let (p, q, g, h) = get_constants(&Group::P256).unwrap();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = exponentiates_points_for_group(&Group::P256, &x_secret, &g, &h, &p).unwrap();
+        let (r1, r2) = exponentiates_points_for_group(&Group::P256, &k, &g, &h, &p).unwrap();
+        let s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(verify_for_group(&Group::P256, &params).unwrap());
+    }
+
+    #[test]
+    fn test_p256_verify_for_group_rejects_a_tampered_proof() {
+        let (p, q, g, h) = get_constants(&Group::P256).unwrap();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = exponentiates_points_for_group(&Group::P256, &x_secret, &g, &h, &p).unwrap();
+        let (r1, r2) = exponentiates_points_for_group(&Group::P256, &k, &g, &h, &p).unwrap();
+        let mut s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+        s = (s + BigUint::from(1u32)) % &q;
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(!verify_for_group(&Group::P256, &params).unwrap());
+    }
+
+    #[test]
+    fn test_secp256k1_group_is_wired_through_get_constants_and_verify_for_group() {
+        let (p, q, g, h) = get_constants(&Group::Secp256k1).unwrap();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = exponentiates_points_for_group(&Group::Secp256k1, &x_secret, &g, &h, &p).unwrap();
+        let (r1, r2) = exponentiates_points_for_group(&Group::Secp256k1, &k, &g, &h, &p).unwrap();
+        let s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(verify_for_group(&Group::Secp256k1, &params).unwrap());
+    }
+
+    #[test]
+    fn test_secp256k1_verify_for_group_rejects_a_tampered_proof() {
+        let (p, q, g, h) = get_constants(&Group::Secp256k1).unwrap();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = exponentiates_points_for_group(&Group::Secp256k1, &x_secret, &g, &h, &p).unwrap();
+        let (r1, r2) = exponentiates_points_for_group(&Group::Secp256k1, &k, &g, &h, &p).unwrap();
+        let mut s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+        s = (s + BigUint::from(1u32)) % &q;
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(!verify_for_group(&Group::Secp256k1, &params).unwrap());
+    }
+
+    #[test]
+    fn test_schnorr_sign_and_verify_roundtrip() {
+        let (p, q, g, _) = get_constants_scalar();
+        let x_secret = BigUint::from(777u32);
+        let message = b"hello schnorr";
+
+        let (r, s) = sign(&x_secret, message, &g, &p, &q).unwrap();
+        let y = commitment::point_pow(&g, &x_secret, &p).unwrap();
+
+        assert!(verify_signature(&y, message, &r, &s, &g, &p, &q).unwrap());
+        assert!(!verify_signature(&y, b"tampered", &r, &s, &g, &p, &q).unwrap());
+    }
+
+    #[test]
+    fn test_prove_and_verify_noninteractive_roundtrip() {
+        let (p, q, g, h) = get_constants_scalar();
+        let x_secret = BigUint::from(42u32);
+        let k = BigUint::from(99u32);
+
+        let (y1, y2, proof) = prove_noninteractive(&x_secret, &k, &g, &h, &p, &q).unwrap();
+        assert!(verify_noninteractive(&proof, &y1, &y2, &g, &h, &p, &q).unwrap());
+
+        let mut tampered = proof.clone();
+        tampered.s = (tampered.s + BigUint::one()) % &q;
+        assert!(!verify_noninteractive(&tampered, &y1, &y2, &g, &h, &p, &q).unwrap());
+    }
+
+    #[test]
+    fn test_verify_with_fixed_base_matches_verify() {
+        let (p, q, g, h) = get_constants_scalar();
+        let x_secret = BigUint::from(55u32);
+        let k = BigUint::from(66u32);
+        let c = BigUint::from(7u32) % &q;
+
+        let (y1, y2) = exponentiates_points(&x_secret, &g, &h, &p).unwrap();
+        let fixed = FixedBaseGenerators::new(&g, &h, q.bits(), &p).unwrap();
+        let (r1, r2) = exponentiates_points_with_fixed_base(&fixed, &k).unwrap();
+        let s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(verify_with_fixed_base(&fixed, &params).unwrap());
+        assert!(verify(&params).unwrap());
+    }
+
     #[test]
     fn test_get_random_array() {
         let a = get_random_array::<32>().unwrap();
@@ -475,6 +1236,28 @@ mod tests {
             prop_assert_eq!(point, deserialized);
         }
 
+        #[test]
+        fn test_serialization_roundtrip_ecpoint(k in 1u32..1000u32) {
+            let scaled = Secp256k1Point::generator().scale(BigUint::from(k));
+            let point = Point::from_secp256k1(&scaled).unwrap();
+
+            let uncompressed = point.serialize_with(PointEncoding::Uncompressed).unwrap();
+            let deserialized = Point::deserialize(uncompressed, &Group::EllipticCurve).unwrap();
+            prop_assert_eq!(&point, &deserialized);
+
+            let compressed = point.serialize_with(PointEncoding::Compressed).unwrap();
+            let deserialized = Point::deserialize(compressed, &Group::EllipticCurve).unwrap();
+            prop_assert_eq!(point, deserialized);
+        }
+
+        #[test]
+        fn test_serialization_roundtrip_group(group_is_elliptic in any::<bool>()) {
+            let group = if group_is_elliptic { Group::EllipticCurve } else { Group::Scalar };
+            let json = serde_json::to_string(&group).unwrap();
+            let decoded: Group = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(format!("{:?}", group), format!("{:?}", decoded));
+        }
+
         #[test]
         fn test_zk_challenge_solution_range(
             x in 1u32..1000u32,
@@ -0,0 +1,386 @@
+//! A Poseidon-flavored Fiat-Shamir transcript over the BN254 scalar field,
+//! for deriving challenges on proofs built over [`BabyJubJubCurve`](crate::curve::BabyJubJubCurve)
+//! so the whole protocol — including challenge derivation — stays cheap to
+//! verify inside a SNARK circuit, where a byte-oriented hash like SHA-256
+//! (as [`Transcript`](crate::transcript::Transcript) uses) is expensive to
+//! express in arithmetic constraints.
+//!
+//! This is a simplified sponge, not the full Poseidon permutation from the
+//! reference paper/circomlib: it uses 2 state elements (matching a rate-1
+//! sponge big enough to absorb one field element per squeeze), a
+//! domain-derived round constant per round instead of the paper's
+//! per-round, per-element constants, and an MDS step that is just a fixed
+//! 2x2 mix rather than a derived MDS matrix. It is internally consistent
+//! (the same sponge absorbs and squeezes), but is not wire-compatible with
+//! circomlib's Poseidon and should not be treated as such; swap in a real
+//! Poseidon implementation with the official round constants/MDS matrix
+//! before using this across a circuit boundary.
+//!
+//! [`prove_noninteractive_poseidon`]/[`verify_noninteractive_poseidon`] are
+//! the BabyJubJub counterpart of [`crate::prove_noninteractive`]/
+//! [`crate::verify_noninteractive`], the same non-interactive Chaum-Pedersen
+//! shape with the Fiat-Shamir hash swapped from SHA-256 to this sponge.
+
+use crate::curve::Curve;
+use crate::traits::GroupOps;
+use num_bigint::BigUint;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+const ROUNDS: u32 = 8;
+
+fn prime() -> BigUint {
+    crate::curve::babyjubjub_prime()
+}
+
+/// Derives the round constant for round `i` from a fixed label and `i`
+/// alone, so every transcript uses the same constant schedule.
+fn round_constant(i: u32, p: &BigUint) -> BigUint {
+    let mut hasher = Sha256::new();
+    hasher.update(b"cpzkp/poseidon-lite/rc");
+    hasher.update(i.to_be_bytes());
+    BigUint::from_bytes_be(&hasher.finalize()) % p
+}
+
+/// A 2-element Poseidon-lite sponge, absorbing one field element at a time.
+pub struct PoseidonTranscript {
+    state: [BigUint; 2],
+    p: BigUint,
+}
+
+impl PoseidonTranscript {
+    /// Starts a new sponge, mixing in a domain label as the initial state.
+    pub fn new(label: &'static [u8]) -> Self {
+        let p = prime();
+        let mut hasher = Sha256::new();
+        hasher.update(label);
+        let iv = BigUint::from_bytes_be(&hasher.finalize()) % &p;
+
+        PoseidonTranscript {
+            state: [iv, BigUint::from(0u32)],
+            p,
+        }
+    }
+
+    /// Absorbs one field element, running the permutation after mixing it
+    /// into the rate element.
+    pub fn absorb(&mut self, element: &BigUint) {
+        self.state[0] = (&self.state[0] + element) % &self.p;
+        self.permute();
+    }
+
+    /// Runs the fixed-round permutation: an additive round constant, the
+    /// `x^5` S-box (Poseidon's usual choice for this field), and a 2x2 MDS
+    /// mix, repeated `ROUNDS` times.
+    fn permute(&mut self) {
+        for i in 0..ROUNDS {
+            let rc = round_constant(i, &self.p);
+            let a = (&self.state[0] + &rc) % &self.p;
+            let b = (&self.state[1] + &rc) % &self.p;
+
+            let a5 = a.modpow(&BigUint::from(5u32), &self.p);
+            let b5 = b.modpow(&BigUint::from(5u32), &self.p);
+
+            self.state[0] = (&a5 * BigUint::from(2u32) + &b5) % &self.p;
+            self.state[1] = (&a5 + &b5 * BigUint::from(3u32)) % &self.p;
+        }
+    }
+
+    /// Squeezes the current rate element as the challenge, reduced modulo
+    /// `order` (BabyJubJub's subgroup order, typically smaller than the
+    /// BN254 scalar field the sponge itself runs over).
+    pub fn challenge(self, order: &BigUint) -> BigUint {
+        self.state[0].clone() % order
+    }
+}
+
+/// A Chaum-Pedersen-shaped non-interactive proof whose challenge was
+/// derived with [`PoseidonTranscript`] rather than [`crate::transcript::Transcript`],
+/// so the transcript binding itself stays cheap to re-derive inside a
+/// circuit that already treats BabyJubJub/Poseidon as native.
+#[derive(Clone)]
+pub struct PoseidonProof {
+    pub r1: crate::types::Point,
+    pub r2: crate::types::Point,
+    pub c: BigUint,
+    pub s: BigUint,
+}
+
+/// Absorbs a BabyJubJub `Point::ECPoint`'s coordinates as two field elements,
+/// the Poseidon-native equivalent of [`crate::transcript::Transcript::absorb_point`].
+fn absorb_point(transcript: &mut PoseidonTranscript, point: &crate::types::Point) -> Result<(), crate::types::Error> {
+    match point {
+        crate::types::Point::ECPoint(x, y) => {
+            transcript.absorb(x);
+            transcript.absorb(y);
+            Ok(())
+        }
+        _ => Err(crate::types::Error::PointTypeMismatch),
+    }
+}
+
+/// Derives the Poseidon-transcript challenge `c = Poseidon(g ‖ h ‖ y1 ‖ y2 ‖
+/// r1 ‖ r2) mod order`, the BabyJubJub counterpart of
+/// [`crate::transcript::challenge_from_transcript`].
+fn poseidon_challenge(
+    g: &crate::types::Point,
+    h: &crate::types::Point,
+    y1: &crate::types::Point,
+    y2: &crate::types::Point,
+    r1: &crate::types::Point,
+    r2: &crate::types::Point,
+    order: &BigUint,
+) -> Result<BigUint, crate::types::Error> {
+    let mut transcript = PoseidonTranscript::new(b"cpzkp/babyjubjub/chaum-pedersen");
+    absorb_point(&mut transcript, g)?;
+    absorb_point(&mut transcript, h)?;
+    absorb_point(&mut transcript, y1)?;
+    absorb_point(&mut transcript, y2)?;
+    absorb_point(&mut transcript, r1)?;
+    absorb_point(&mut transcript, r2)?;
+    Ok(transcript.challenge(order))
+}
+
+/// The BabyJubJub/Poseidon analogue of [`crate::prove_noninteractive`]: same
+/// Chaum-Pedersen shape, but the challenge is bound via [`PoseidonTranscript`]
+/// instead of the default SHA-256 [`crate::transcript::Transcript`].
+pub fn prove_noninteractive_poseidon(
+    x_secret: &BigUint,
+    k: &BigUint,
+) -> Result<(crate::types::Point, crate::types::Point, PoseidonProof), crate::types::Error> {
+    use crate::curve::{BabyJubJubCurve, Curve};
+
+    let curve = BabyJubJubCurve;
+    let g = curve.generator();
+    let h = curve.scalar_mul(&g, &BigUint::from(13u32))?;
+    let order = curve.order();
+
+    let y1 = curve.scalar_mul(&g, x_secret)?;
+    let y2 = curve.scalar_mul(&h, x_secret)?;
+    let r1 = curve.scalar_mul(&g, k)?;
+    let r2 = curve.scalar_mul(&h, k)?;
+
+    let c = poseidon_challenge(&g, &h, &y1, &y2, &r1, &r2, &order)?;
+    let s = crate::solve_zk_challenge_s(x_secret, k, &c, &order);
+
+    Ok((y1, y2, PoseidonProof { r1, r2, c, s }))
+}
+
+/// Verifies a [`PoseidonProof`] by recomputing `c` over the same Poseidon
+/// transcript and checking `r1 == g^s·y1^c`, `r2 == h^s·y2^c`.
+pub fn verify_noninteractive_poseidon(
+    proof: &PoseidonProof,
+    y1: &crate::types::Point,
+    y2: &crate::types::Point,
+) -> Result<bool, crate::types::Error> {
+    use crate::curve::{BabyJubJubCurve, Curve};
+
+    let curve = BabyJubJubCurve;
+    let g = curve.generator();
+    let h = curve.scalar_mul(&g, &BigUint::from(13u32))?;
+    let order = curve.order();
+
+    let c = poseidon_challenge(&g, &h, y1, y2, &proof.r1, &proof.r2, &order)?;
+    if c != proof.c {
+        return Ok(false);
+    }
+
+    let g_s = curve.scalar_mul(&g, &proof.s)?;
+    let y1_c = curve.scalar_mul(y1, &proof.c)?;
+    let condition_1 = proof.r1 == curve.add(&g_s, &y1_c)?;
+
+    let h_s = curve.scalar_mul(&h, &proof.s)?;
+    let y2_c = curve.scalar_mul(y2, &proof.c)?;
+    let condition_2 = proof.r2 == curve.add(&h_s, &y2_c)?;
+
+    Ok(condition_1 && condition_2)
+}
+
+/// Checks a point against BabyJubJub's defining equation `a·x² + y² = 1 +
+/// d·x²·y² mod p`, used by [`crate::Point::deserialize_into_babyjubjub`] to
+/// reject off-curve points on decode.
+pub(crate) fn babyjubjub_point_is_on_curve(point: &crate::types::Point) -> bool {
+    const BABYJUBJUB_A: u32 = 168700;
+    const BABYJUBJUB_D: u32 = 168696;
+
+    match point {
+        crate::types::Point::ECPoint(x, y) => {
+            let p = prime();
+            let a = BigUint::from(BABYJUBJUB_A);
+            let d = BigUint::from(BABYJUBJUB_D);
+
+            let x2 = x.modpow(&BigUint::from(2u32), &p);
+            let y2 = y.modpow(&BigUint::from(2u32), &p);
+            let lhs = (&a * &x2 + &y2) % &p;
+            let rhs = (BigUint::from(1u32) + &d * &x2 * &y2) % &p;
+            lhs == rhs
+        }
+        _ => false,
+    }
+}
+
+/// `GroupOps`/`ZkpOps` over BabyJubJub, the Poseidon-challenge counterpart
+/// of [`crate::ecc::Secp256k1GroupOps`]: same trait shape, but
+/// `challenge_from_transcript` (and `verify_proof`, to stay consistent with
+/// it) goes through [`poseidon_challenge`] instead of
+/// [`crate::transcript::challenge_from_transcript`]'s SHA-256 transcript.
+pub struct BabyJubJubGroupOps;
+
+impl crate::traits::GroupOps for BabyJubJubGroupOps {
+    fn prime(&self) -> BigUint {
+        crate::curve::BabyJubJubCurve.prime()
+    }
+
+    fn order(&self) -> BigUint {
+        crate::curve::BabyJubJubCurve.order()
+    }
+
+    fn generator(&self) -> crate::types::Point {
+        crate::curve::BabyJubJubCurve.generator()
+    }
+
+    fn second_generator(&self) -> crate::types::Point {
+        crate::curve::BabyJubJubCurve
+            .scalar_mul(&crate::curve::BabyJubJubCurve.generator(), &BigUint::from(13u32))
+            .expect("scaling the generator by a small scalar never reaches the identity")
+    }
+}
+
+impl crate::traits::ZkpOps for BabyJubJubGroupOps {
+    fn generate_challenge(&self) -> Result<BigUint, crate::types::Error> {
+        let mut arr = [0u8; 32];
+        rand::thread_rng()
+            .try_fill_bytes(&mut arr)
+            .map_err(|e| crate::types::Error::RandomGenerationError(e.to_string()))?;
+        Ok(BigUint::from_bytes_be(&arr) % self.order())
+    }
+
+    fn solve_challenge(&self, secret: &BigUint, random: &BigUint, challenge: &BigUint) -> BigUint {
+        crate::solve_zk_challenge_s(secret, random, challenge, &self.order())
+    }
+
+    fn verify_proof(&self, params: &crate::types::VerificationParams) -> Result<bool, crate::types::Error> {
+        let order = self.order();
+        let c = poseidon_challenge(&params.g, &params.h, &params.y1, &params.y2, &params.r1, &params.r2, &order)?;
+        if c != params.c {
+            return Ok(false);
+        }
+
+        use crate::curve::{BabyJubJubCurve, Curve};
+        let curve = BabyJubJubCurve;
+        let lhs1 = curve.add(&curve.scalar_mul(&params.g, &params.s)?, &curve.scalar_mul(&params.y1, &params.c)?)?;
+        let lhs2 = curve.add(&curve.scalar_mul(&params.h, &params.s)?, &curve.scalar_mul(&params.y2, &params.c)?)?;
+        Ok(lhs1 == params.r1 && lhs2 == params.r2)
+    }
+
+    fn challenge_from_transcript(
+        &self,
+        g: &crate::types::Point,
+        h: &crate::types::Point,
+        y1: &crate::types::Point,
+        y2: &crate::types::Point,
+        r1: &crate::types::Point,
+        r2: &crate::types::Point,
+        _message: Option<&[u8]>,
+    ) -> BigUint {
+        // The Poseidon sponge absorbs field elements, not arbitrary byte
+        // strings, so (unlike the SHA-256 transcript) an application
+        // message isn't threaded through here; callers needing to bind one
+        // should absorb it as a field element via their own `PoseidonTranscript`.
+        poseidon_challenge(g, h, y1, y2, r1, r2, &self.order())
+            .expect("g/h/y1/y2/r1/r2 are always Point::ECPoint for this group")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::BabyJubJubCurve;
+
+    #[test]
+    fn same_inputs_yield_same_challenge() {
+        let order = BigUint::from(10_000_019u32);
+
+        let mut t1 = PoseidonTranscript::new(b"cpzkp/test");
+        t1.absorb(&BigUint::from(42u32));
+        let c1 = t1.challenge(&order);
+
+        let mut t2 = PoseidonTranscript::new(b"cpzkp/test");
+        t2.absorb(&BigUint::from(42u32));
+        let c2 = t2.challenge(&order);
+
+        assert_eq!(c1, c2);
+    }
+
+    #[test]
+    fn different_inputs_yield_different_challenges() {
+        let order = BigUint::from(10_000_019u32);
+
+        let mut t1 = PoseidonTranscript::new(b"cpzkp/test");
+        t1.absorb(&BigUint::from(42u32));
+        let c1 = t1.challenge(&order);
+
+        let mut t2 = PoseidonTranscript::new(b"cpzkp/test");
+        t2.absorb(&BigUint::from(43u32));
+        let c2 = t2.challenge(&order);
+
+        assert_ne!(c1, c2);
+    }
+
+    #[test]
+    fn prove_and_verify_noninteractive_poseidon_roundtrip() {
+        let x = BigUint::from(321u32);
+        let k = BigUint::from(654u32);
+
+        let (y1, y2, proof) = prove_noninteractive_poseidon(&x, &k).unwrap();
+        assert!(verify_noninteractive_poseidon(&proof, &y1, &y2).unwrap());
+    }
+
+    #[test]
+    fn verify_noninteractive_poseidon_rejects_a_tampered_proof() {
+        let x = BigUint::from(321u32);
+        let k = BigUint::from(654u32);
+
+        let (y1, y2, mut proof) = prove_noninteractive_poseidon(&x, &k).unwrap();
+        proof.s = (proof.s + BigUint::from(1u32)) % BigUint::from(10u32);
+
+        assert!(!verify_noninteractive_poseidon(&proof, &y1, &y2).unwrap());
+    }
+
+    #[test]
+    fn babyjubjub_group_ops_verify_proof_matches_poseidon_challenge() {
+        use crate::traits::{GroupOps, ZkpOps};
+
+        let group = BabyJubJubGroupOps;
+        let x_secret = BigUint::from(321u32);
+        let k = BigUint::from(654u32);
+
+        let (y1, y2, proof) = prove_noninteractive_poseidon(&x_secret, &k).unwrap();
+        let curve = BabyJubJubCurve;
+        let g = curve.generator();
+        let h = curve.scalar_mul(&g, &BigUint::from(13u32)).unwrap();
+
+        let params = crate::types::VerificationParams {
+            r1: proof.r1,
+            r2: proof.r2,
+            y1,
+            y2,
+            g,
+            h,
+            c: proof.c,
+            s: proof.s,
+            p: group.prime(),
+        };
+        assert!(group.verify_proof(&params).unwrap());
+    }
+
+    #[test]
+    fn babyjubjub_point_on_curve_check_accepts_the_generator_and_rejects_garbage() {
+        let curve = BabyJubJubCurve;
+        assert!(babyjubjub_point_is_on_curve(&curve.generator()));
+        assert!(!babyjubjub_point_is_on_curve(&crate::types::Point::ECPoint(
+            BigUint::from(1u32),
+            BigUint::from(1u32)
+        )));
+    }
+}
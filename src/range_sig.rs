@@ -0,0 +1,461 @@
+//! Signature-based range proof, after Camenisch-Chaabouni-shelat (Asiacrypt
+//! '08): proves a committed witness `v` lies in `[0, u^l)` without revealing
+//! it, by pre-signing every digit value and having the prover demonstrate
+//! possession of a blinded signature on each digit of `v`'s base-`u`
+//! expansion.
+//!
+//! The original CCS08 construction signs digits with a CL signature over a
+//! pairing-friendly group so the signature can be re-randomized; this crate
+//! has no pairing backend, so `sign_digit` below stands in with an HMAC-like
+//! keyed hash over the toy scalar group. What ties a proof to an actual
+//! valid `(digit, sigma)` pair is a [Cramer-Damgård-Schoenmakers][cds]
+//! OR-proof over all `u` possible digits, in the same style as
+//! [`crate::bit_range::BitRangeProof`]'s bit-level OR-proof, generalized
+//! from 2 branches to `u` and from one committed value per branch to two
+//! (the digit and its signature, linked by a shared blinding factor so
+//! proving one also proves the other).
+//!
+//! [cds]: https://doi.org/10.1007/3-540-48658-5_19
+
+use crate::commitment::{point_combine, point_pow, CSMultiParams};
+use crate::transcript::Transcript;
+use crate::types::{Error, Point};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+/// Verifier-side parameters: one signature per digit value `d ∈ {0,…,u-1}`
+/// under a fresh one-time signing key, plus the Pedersen generators used to
+/// commit to each digit (and its signature) when proving.
+pub struct RangeParams {
+    /// Base of the digit decomposition (e.g. `u = 2` for bits).
+    pub u: u32,
+    /// Number of digits, so the proven range is `[0, u^l)`.
+    pub l: u32,
+    /// One-time signing key for the digit signatures.
+    signing_key: [u8; 32],
+    /// `digit_signatures[d]` signs the digit value `d`.
+    digit_signatures: Vec<BigUint>,
+    /// Generators: `[0]` commits a digit, `[1]` commits its signature, `h`
+    /// blinds both (the same blinding factor is reused for a digit's value
+    /// and signature commitments, which is what lets one OR-proof bind
+    /// them together).
+    commit_params: CSMultiParams,
+}
+
+impl RangeParams {
+    /// Generates fresh parameters for base-`u`, `l`-digit range proofs,
+    /// pre-signing every digit `0..u`.
+    pub fn new(u: u32, l: u32, commit_params: CSMultiParams) -> Self {
+        let signing_key = rand::random::<[u8; 32]>();
+        let digit_signatures = (0..u).map(|d| sign_digit(&signing_key, d)).collect();
+
+        RangeParams {
+            u,
+            l,
+            signing_key,
+            digit_signatures,
+            commit_params,
+        }
+    }
+
+    fn signature_for(&self, digit: u32) -> Result<&BigUint, Error> {
+        self.digit_signatures
+            .get(digit as usize)
+            .ok_or(Error::InvalidArguments)
+    }
+
+    fn g_value(&self) -> &Point {
+        &self.commit_params.generators[0]
+    }
+
+    fn g_sig(&self) -> &Point {
+        &self.commit_params.generators[1]
+    }
+
+    /// Computes the Pedersen commitment `g_value^x · h^blinding` a prover
+    /// and verifier agree on out of band as the public value [`RangeProof`]
+    /// is proving `x` (the range witness) opens.
+    pub fn commit_value(&self, x: &BigUint, blinding: &BigUint) -> Result<Point, Error> {
+        point_combine(
+            &point_pow(self.g_value(), x, &self.commit_params.p)?,
+            &point_pow(&self.commit_params.h, blinding, &self.commit_params.p)?,
+            &self.commit_params.p,
+        )
+    }
+}
+
+/// Signs a digit value under the one-time key: `sigma = H(key ‖ digit)`.
+fn sign_digit(key: &[u8; 32], digit: u32) -> BigUint {
+    let mut hasher = Sha256::new();
+    hasher.update(key);
+    hasher.update(digit.to_be_bytes());
+    BigUint::from_bytes_be(&hasher.finalize())
+}
+
+/// Verifies a digit signature against the one-time key (only the verifier
+/// who generated `RangeParams` can do this directly; [`DigitOrProof`]
+/// is how a prover instead shows knowledge of a valid `(digit, sigma)` pair
+/// without revealing which).
+fn verify_digit(key: &[u8; 32], digit: u32, sigma: &BigUint) -> bool {
+    sign_digit(key, digit) == *sigma
+}
+
+/// Raises `point` to the `-1` power: `point^{order - 1}`, since
+/// `point^order` is the group identity. Mirrors
+/// [`crate::bit_range::invert`]'s trick of expressing point negation as
+/// exponentiation, so it works uniformly across `Point::Scalar` and
+/// `Point::ECPoint` without a separate per-variant negation.
+fn invert(point: &Point, p: &BigUint, order: &BigUint) -> Result<Point, Error> {
+    point_pow(point, &(order - BigUint::from(1u32)), p)
+}
+
+fn random_scalar(order: &BigUint) -> BigUint {
+    BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % order
+}
+
+/// One branch (candidate digit `d`) of a [`DigitOrProof`]: a Chaum-Pedersen
+/// pair of Schnorr commitments sharing one nonce/response, since both
+/// equations below are proofs of knowledge of the same blinding factor.
+struct DigitBranch {
+    /// Schnorr commitment for `value_commitment / g_value^d == h^blind`.
+    a_value: Point,
+    /// Schnorr commitment for `sig_commitment / g_sig^{sigma_d} == h^blind`.
+    a_sig: Point,
+    /// This branch's share of the overall Fiat-Shamir challenge.
+    c: BigUint,
+    /// Shared response for both equations.
+    z: BigUint,
+}
+
+/// An OR-proof that a linked `(value_commitment, sig_commitment)` pair
+/// opens to `(d, sigma_d, blind)` for *some* `d ∈ {0,…,u-1}` with
+/// `sigma_d` a valid pre-signed signature on `d` — without revealing which
+/// `d`. Only the true branch is computed honestly; the rest are simulated
+/// and the true branch's challenge is solved so every branch's challenge
+/// sums to the single Fiat-Shamir challenge `c`, exactly as
+/// [`crate::bit_range::BitProof`] does for its two branches.
+struct DigitOrProof {
+    branches: Vec<DigitBranch>,
+}
+
+impl DigitOrProof {
+    /// Derives the challenge binding every branch's Schnorr commitments to
+    /// the two commitments they're proving knowledge of an opening for.
+    fn challenge(value_commitment: &Point, sig_commitment: &Point, branches: &[(Point, Point)], order: &BigUint) -> BigUint {
+        let mut transcript = Transcript::new(b"cpzkp/range-sig-digit");
+        transcript.absorb_point(value_commitment);
+        transcript.absorb_point(sig_commitment);
+        for (a_value, a_sig) in branches {
+            transcript.absorb_point(a_value);
+            transcript.absorb_point(a_sig);
+        }
+        transcript.challenge(order)
+    }
+
+    /// Proves that `value_commitment = g_value^{digit}·h^{blind}` and
+    /// `sig_commitment = g_sig^{sigma}·h^{blind}` for the true `digit` and
+    /// its (valid, pre-signed) signature `sigma`, without revealing
+    /// `digit`.
+    fn prove(
+        params: &RangeParams,
+        digit: u32,
+        blind: &BigUint,
+        value_commitment: &Point,
+        sig_commitment: &Point,
+    ) -> Result<Self, Error> {
+        let p = &params.commit_params.p;
+        let order = &params.commit_params.q;
+
+        // Simulate every branch but the true digit: pick the response and
+        // this branch's share of the challenge at random, then solve for
+        // the Schnorr commitments that make both equations check out.
+        let mut cs = vec![BigUint::from(0u32); params.u as usize];
+        let mut zs = vec![BigUint::from(0u32); params.u as usize];
+        let mut as_pairs = vec![(Point::Scalar(BigUint::from(0u32)), Point::Scalar(BigUint::from(0u32))); params.u as usize];
+        let honest_nonce = random_scalar(order);
+
+        for d in 0..params.u {
+            if d == digit {
+                let a_honest = point_pow(&params.commit_params.h, &honest_nonce, p)?;
+                as_pairs[d as usize] = (a_honest.clone(), a_honest);
+                continue;
+            }
+
+            let sigma_d = params.signature_for(d)?;
+            let target_value = point_combine(value_commitment, &invert(&point_pow(params.g_value(), &BigUint::from(d), p)?, p, order)?, p)?;
+            let target_sig = point_combine(sig_commitment, &invert(&point_pow(params.g_sig(), sigma_d, p)?, p, order)?, p)?;
+
+            let c_d = random_scalar(order);
+            let z_d = random_scalar(order);
+            let a_value = point_combine(&point_pow(&params.commit_params.h, &z_d, p)?, &invert(&point_pow(&target_value, &c_d, p)?, p, order)?, p)?;
+            let a_sig = point_combine(&point_pow(&params.commit_params.h, &z_d, p)?, &invert(&point_pow(&target_sig, &c_d, p)?, p, order)?, p)?;
+            cs[d as usize] = c_d;
+            zs[d as usize] = z_d;
+            as_pairs[d as usize] = (a_value, a_sig);
+        }
+
+        let c = Self::challenge(value_commitment, sig_commitment, &as_pairs, order);
+        let simulated_sum: BigUint = cs.iter().fold(BigUint::from(0u32), |acc, c_d| acc + c_d) % order;
+        let c_honest = (&c + order - &simulated_sum) % order;
+        let z_honest = (&honest_nonce + &c_honest * blind) % order;
+        cs[digit as usize] = c_honest;
+        zs[digit as usize] = z_honest;
+
+        let branches = (0..params.u)
+            .map(|d| {
+                let (a_value, a_sig) = as_pairs[d as usize].clone();
+                DigitBranch {
+                    a_value,
+                    a_sig,
+                    c: cs[d as usize].clone(),
+                    z: zs[d as usize].clone(),
+                }
+            })
+            .collect();
+
+        Ok(DigitOrProof { branches })
+    }
+
+    /// Verifies every branch's Schnorr equation and that the branch
+    /// challenges sum to the Fiat-Shamir challenge for `value_commitment`/
+    /// `sig_commitment`.
+    fn verify(&self, params: &RangeParams, value_commitment: &Point, sig_commitment: &Point) -> Result<bool, Error> {
+        if self.branches.len() != params.u as usize {
+            return Ok(false);
+        }
+
+        let p = &params.commit_params.p;
+        let order = &params.commit_params.q;
+
+        let as_pairs: Vec<(Point, Point)> = self
+            .branches
+            .iter()
+            .map(|b| (b.a_value.clone(), b.a_sig.clone()))
+            .collect();
+        let c = Self::challenge(value_commitment, sig_commitment, &as_pairs, order);
+
+        let c_sum: BigUint = self.branches.iter().fold(BigUint::from(0u32), |acc, b| acc + &b.c) % order;
+        if c_sum != c {
+            return Ok(false);
+        }
+
+        for (d, branch) in self.branches.iter().enumerate() {
+            let sigma_d = params.signature_for(d as u32)?;
+            let target_value = point_combine(value_commitment, &invert(&point_pow(params.g_value(), &BigUint::from(d as u32), p)?, p, order)?, p)?;
+            let target_sig = point_combine(sig_commitment, &invert(&point_pow(params.g_sig(), sigma_d, p)?, p, order)?, p)?;
+
+            let lhs = point_pow(&params.commit_params.h, &branch.z, p)?;
+            let rhs_value = point_combine(&branch.a_value, &point_pow(&target_value, &branch.c, p)?, p)?;
+            if lhs != rhs_value {
+                return Ok(false);
+            }
+            let rhs_sig = point_combine(&branch.a_sig, &point_pow(&target_sig, &branch.c, p)?, p)?;
+            if lhs != rhs_sig {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Proof that one digit's value and signature commitments open to a valid
+/// `(digit, sigma)` pair, without revealing which digit.
+struct DigitProof {
+    /// Commitment to `(digit, blind)`.
+    value_commitment: Point,
+    /// Commitment to `(sigma, blind)`, the same `blind` as above.
+    sig_commitment: Point,
+    /// Proof the two commitments above open to a matching, validly-signed
+    /// digit.
+    or_proof: DigitOrProof,
+}
+
+/// A signature-based range proof over `[0, u^l)`.
+pub struct RangeProof {
+    digit_proofs: Vec<DigitProof>,
+    /// `blinding - Σ blind_j·u^j mod order`: the gap between the digit
+    /// blinds (chosen independently of `blinding`, since `u^j` need not be
+    /// invertible mod the group order for every `u`/order pair) and the
+    /// blinding the proof is bound to. Revealing it leaks nothing about
+    /// `x`, since it's masked by `blind_0` (weight `u^0 = 1`), which is
+    /// uniform and independent of every digit.
+    reconciliation: BigUint,
+}
+
+impl RangeProof {
+    /// Proves that `x` lies in `[0, u^l)` by decomposing it into digits,
+    /// committing each digit and its pre-signed signature, and proving
+    /// each commitment pair opens to a validly-signed digit. Binds the
+    /// whole proof to [`RangeParams::commit_value`]'s `g_value^x·h^blinding`
+    /// via `reconciliation`, the gap between `blinding` and the digit
+    /// blinds' own weighted sum.
+    pub fn prove(params: &RangeParams, x: &BigUint, blinding: &BigUint) -> Result<Self, Error> {
+        let digits = decompose(x, params.u, params.l)?;
+        let order = &params.commit_params.q;
+        let p = &params.commit_params.p;
+
+        let blinds: Vec<BigUint> = (0..digits.len()).map(|_| random_scalar(order)).collect();
+        let weighted_sum: BigUint = blinds
+            .iter()
+            .enumerate()
+            .fold(BigUint::from(0u32), |acc, (j, blind_j)| {
+                (acc + blind_j * BigUint::from(params.u).pow(j as u32)) % order
+            });
+        let reconciliation = (order + blinding - (&weighted_sum % order)) % order;
+
+        let mut digit_proofs = Vec::with_capacity(digits.len());
+        for (digit, blind) in digits.into_iter().zip(blinds.into_iter()) {
+            let sigma = params.signature_for(digit)?.clone();
+
+            let value_commitment = point_combine(
+                &point_pow(params.g_value(), &BigUint::from(digit), p)?,
+                &point_pow(&params.commit_params.h, &blind, p)?,
+                p,
+            )?;
+            let sig_commitment = point_combine(
+                &point_pow(params.g_sig(), &sigma, p)?,
+                &point_pow(&params.commit_params.h, &blind, p)?,
+                p,
+            )?;
+
+            let or_proof = DigitOrProof::prove(params, digit, &blind, &value_commitment, &sig_commitment)?;
+
+            digit_proofs.push(DigitProof {
+                value_commitment,
+                sig_commitment,
+                or_proof,
+            });
+        }
+
+        Ok(RangeProof { digit_proofs, reconciliation })
+    }
+
+    /// Verifies the proof has exactly `l` digit commitments, that they
+    /// recombine (weighted by `u^j`, plus `h^reconciliation`) to
+    /// `commitment`, and that each one's OR-proof shows knowledge of a
+    /// validly-signed digit.
+    pub fn verify(&self, params: &RangeParams, commitment: &Point) -> Result<bool, Error> {
+        if self.digit_proofs.len() != params.l as usize {
+            return Ok(false);
+        }
+
+        let p = &params.commit_params.p;
+        let mut recombined: Option<Point> = None;
+        for (j, digit_proof) in self.digit_proofs.iter().enumerate() {
+            let weight = BigUint::from(params.u).pow(j as u32);
+            let weighted = point_pow(&digit_proof.value_commitment, &weight, p)?;
+            recombined = Some(match recombined {
+                Some(acc) => point_combine(&acc, &weighted, p)?,
+                None => weighted,
+            });
+        }
+        let recombined = point_combine(
+            &recombined.ok_or(Error::InvalidArguments)?,
+            &point_pow(&params.commit_params.h, &self.reconciliation, p)?,
+            p,
+        )?;
+        if &recombined != commitment {
+            return Ok(false);
+        }
+
+        for digit_proof in &self.digit_proofs {
+            if !digit_proof.or_proof.verify(params, &digit_proof.value_commitment, &digit_proof.sig_commitment)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Decomposes `v` into `l` base-`u` digits, least-significant first, failing
+/// if `v` does not fit in `[0, u^l)`.
+fn decompose(v: &BigUint, u: u32, l: u32) -> Result<Vec<u32>, Error> {
+    let mut remaining = v.clone();
+    let base = BigUint::from(u);
+    let mut digits = Vec::with_capacity(l as usize);
+
+    for _ in 0..l {
+        let digit = &remaining % &base;
+        digits.push(digit.to_u32_digits().first().copied().unwrap_or(0));
+        remaining /= &base;
+    }
+
+    if remaining != BigUint::from(0u32) {
+        return Err(Error::InvalidArguments);
+    }
+
+    Ok(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_params(u: u32, l: u32) -> RangeParams {
+        let p = BigUint::from(10_000_019u32);
+        let q = BigUint::from(10_000_019u32 - 1);
+        let g_value = Point::Scalar(BigUint::from(2u32));
+        let g_sig = Point::Scalar(BigUint::from(3u32));
+        let h = Point::Scalar(BigUint::from(5u32));
+        let commit_params = CSMultiParams::new(vec![g_value, g_sig], h, p, q);
+        RangeParams::new(u, l, commit_params)
+    }
+
+    #[test]
+    fn digit_signatures_round_trip() {
+        let key = rand::random::<[u8; 32]>();
+        let sigma = sign_digit(&key, 7);
+        assert!(verify_digit(&key, 7, &sigma));
+        assert!(!verify_digit(&key, 8, &sigma));
+    }
+
+    #[test]
+    fn decompose_rejects_out_of_range_values() {
+        let v = BigUint::from(300u32);
+        assert!(decompose(&v, 2, 8).is_err());
+        assert!(decompose(&v, 2, 9).is_ok());
+    }
+
+    #[test]
+    fn range_proof_accepts_a_value_in_range() {
+        let params = test_params(4, 4);
+        let x = BigUint::from(37u32);
+        let blinding = BigUint::from(123_456u32);
+        let commitment = params.commit_value(&x, &blinding).unwrap();
+
+        let proof = RangeProof::prove(&params, &x, &blinding).unwrap();
+        assert!(proof.verify(&params, &commitment).unwrap());
+    }
+
+    #[test]
+    fn range_proof_rejects_a_mismatched_commitment() {
+        let params = test_params(4, 4);
+        let x = BigUint::from(37u32);
+        let blinding = BigUint::from(123_456u32);
+        let other_commitment = params.commit_value(&BigUint::from(38u32), &blinding).unwrap();
+
+        let proof = RangeProof::prove(&params, &x, &blinding).unwrap();
+        assert!(!proof.verify(&params, &other_commitment).unwrap());
+    }
+
+    #[test]
+    fn range_proof_rejects_a_tampered_digit_commitment() {
+        let params = test_params(4, 4);
+        let x = BigUint::from(37u32);
+        let blinding = BigUint::from(123_456u32);
+        let commitment = params.commit_value(&x, &blinding).unwrap();
+
+        let mut proof = RangeProof::prove(&params, &x, &blinding).unwrap();
+        proof.digit_proofs[0].value_commitment = params.commit_value(&BigUint::from(1u32), &BigUint::from(1u32)).unwrap();
+        assert!(!proof.verify(&params, &commitment).unwrap());
+    }
+
+    #[test]
+    fn prove_rejects_an_out_of_range_value() {
+        let params = test_params(2, 8);
+        let x = BigUint::from(300u32);
+        assert!(RangeProof::prove(&params, &x, &BigUint::from(1u32)).is_err());
+    }
+}
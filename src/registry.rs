@@ -0,0 +1,100 @@
+//! A trait-object-based curve registry built on the existing [`Curve`]
+//! abstraction from [`crate::curve`].
+//!
+//! `get_constants`/`exponentiates_points`/`verify` in the crate root add a
+//! new `match` arm for every curve (`Point::Scalar`, `Point::ECPoint`,
+//! `Point::Ristretto`, …), so picking up a new backend means touching all
+//! three functions plus the `Group` enum. The functions here take a
+//! `&dyn Curve` instead and drive everything through its `generator`/`add`/
+//! `scalar_mul` methods, so a brand-new backend only has to provide one
+//! `impl Curve` — as [`P256Curve`](crate::curve::P256Curve) already does —
+//! and nothing here needs to change.
+//!
+//! This is the one curve-abstraction path the crate actually wires in:
+//! [`Group::P256`](crate::Group::P256) reaches [`get_constants_for_curve`]
+//! through [`get_constants_p256`](crate::get_constants_p256), and
+//! [`verify_with_curve`]/[`exponentiates_points_for_curve`] back
+//! [`verify_for_group`](crate::verify_for_group)/
+//! [`exponentiates_points_for_group`](crate::exponentiates_points_for_group)
+//! for that group. An earlier, parallel `CyclicGroup` trait explored the same
+//! problem with compile-time generics instead of `&dyn Curve`, but couldn't
+//! actually back the runtime-valued `Group` enum without a type parameter
+//! threaded through every caller, so it was dropped in favor of this one.
+
+use crate::curve::Curve;
+use crate::types::{Error, Point, VerificationParams};
+use num_bigint::BigUint;
+
+/// Returns `(p, q, g, h)` for `curve`, deriving the second generator as
+/// `13·g`, the same convention [`get_constants_elliptic_curve`](crate::get_constants_elliptic_curve)
+/// uses for secp256k1.
+pub fn get_constants_for_curve(curve: &dyn Curve) -> Result<(BigUint, BigUint, Point, Point), Error> {
+    let g = curve.generator();
+    let h = curve.scalar_mul(&g, &BigUint::from(13u32))?;
+    Ok((curve.prime(), curve.order(), g, h))
+}
+
+/// Scales `g` and `h` by `exp` through `curve`'s own scalar multiplication.
+pub fn exponentiates_points_for_curve(
+    curve: &dyn Curve,
+    exp: &BigUint,
+    g: &Point,
+    h: &Point,
+) -> Result<(Point, Point), Error> {
+    Ok((curve.scalar_mul(g, exp)?, curve.scalar_mul(h, exp)?))
+}
+
+/// Verifies a Chaum-Pedersen proof against `curve`, checking
+/// `r1 == s·g + c·y1` and `r2 == s·h + c·y2` purely through `curve`'s point
+/// arithmetic, with no knowledge of which `Point` variant it uses.
+pub fn verify_with_curve(curve: &dyn Curve, params: &VerificationParams) -> Result<bool, Error> {
+    let sg = curve.scalar_mul(&params.g, &params.s)?;
+    let sh = curve.scalar_mul(&params.h, &params.s)?;
+    let cy1 = curve.scalar_mul(&params.y1, &params.c)?;
+    let cy2 = curve.scalar_mul(&params.y2, &params.c)?;
+
+    let condition_1 = params.r1 == curve.add(&sg, &cy1)?;
+    let condition_2 = params.r2 == curve.add(&sh, &cy2)?;
+
+    Ok(condition_1 && condition_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::P256Curve;
+    use crate::solve_zk_challenge_s;
+
+    #[test]
+    fn verify_with_curve_accepts_a_valid_p256_proof() {
+        let curve = P256Curve;
+        let (p, q, g, h) = get_constants_for_curve(&curve).unwrap();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = exponentiates_points_for_curve(&curve, &x_secret, &g, &h).unwrap();
+        let (r1, r2) = exponentiates_points_for_curve(&curve, &k, &g, &h).unwrap();
+        let s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(verify_with_curve(&curve, &params).unwrap());
+    }
+
+    #[test]
+    fn verify_with_curve_rejects_a_tampered_p256_proof() {
+        let curve = P256Curve;
+        let (p, q, g, h) = get_constants_for_curve(&curve).unwrap();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = exponentiates_points_for_curve(&curve, &x_secret, &g, &h).unwrap();
+        let (r1, r2) = exponentiates_points_for_curve(&curve, &k, &g, &h).unwrap();
+        let mut s = solve_zk_challenge_s(&x_secret, &k, &c, &q);
+        s = (s + BigUint::from(1u32)) % &q;
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        assert!(!verify_with_curve(&curve, &params).unwrap());
+    }
+}
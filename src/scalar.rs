@@ -43,13 +43,15 @@ impl PointOps for Point {
                 x.append(&mut y);
                 x
             }
+            Point::Ristretto(bytes) => bytes.to_vec(),
+            Point::Identity => Vec::new(),
         }
     }
 
     fn deserialize(bytes: Vec<u8>, group: &Group) -> Result<Point, Error> {
         match group {
             Group::Scalar => Ok(Point::Scalar(BigUint::from_bytes_be(&bytes))),
-            Group::EllipticCurve => {
+            Group::EllipticCurve | Group::Secp256k1 => {
                 let len = bytes.len();
                 if len % 2 != 0 {
                     return Err(Error::InvalidSerialization(
@@ -68,6 +70,8 @@ impl PointOps for Point {
         match self {
             Point::Scalar(_) => true,
             Point::ECPoint(_, _) => false,
+            Point::Ristretto(_) => false,
+            Point::Identity => false,
         }
     }
 
@@ -75,6 +79,8 @@ impl PointOps for Point {
         match self {
             Point::Scalar(x) => Point::Scalar(x.clone()),
             Point::ECPoint(_, _) => panic!("Cannot double ECPoint in scalar group"),
+            Point::Ristretto(_) => panic!("Cannot double a Ristretto point in the scalar group"),
+            Point::Identity => panic!("Cannot double the identity point in the scalar group"),
         }
     }
 
@@ -82,6 +88,17 @@ impl PointOps for Point {
         match self {
             Point::Scalar(x) => Point::Scalar(x.modpow(&scalar, &BigUint::from(10009u32))),
             Point::ECPoint(_, _) => panic!("Cannot scale ECPoint in scalar group"),
+            Point::Ristretto(_) => panic!("Cannot scale a Ristretto point in the scalar group"),
+            Point::Identity => panic!("Cannot scale the identity point in the scalar group"),
+        }
+    }
+
+    fn add(&self, other: &Point) -> Point {
+        match (self, other) {
+            (Point::Scalar(a), Point::Scalar(b)) => {
+                Point::Scalar((a * b) % BigUint::from(10009u32))
+            }
+            _ => panic!("Cannot add incompatible point types in scalar group"),
         }
     }
 }
@@ -137,4 +154,17 @@ impl ZkpOps for ScalarGroup {
             Err(Error::PointTypeMismatch)
         }
     }
+
+    fn challenge_from_transcript(
+        &self,
+        g: &Point,
+        h: &Point,
+        y1: &Point,
+        y2: &Point,
+        r1: &Point,
+        r2: &Point,
+        message: Option<&[u8]>,
+    ) -> BigUint {
+        crate::transcript::challenge_from_transcript(g, h, y1, y2, r1, r2, message, &self.order())
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,63 @@
+//! A wrapper for secret scalars (the authentication secret `x` and the
+//! per-round nonce `k`) that zeroizes its backing limbs on drop, closing the
+//! side channel of a plain `BigUint` lingering in memory after it is no
+//! longer needed.
+
+use num_bigint::BigUint;
+use std::fmt;
+use zeroize::Zeroize;
+
+/// A secret scalar that overwrites itself when dropped.
+pub struct Secret(BigUint);
+
+impl Secret {
+    /// Wraps `value` as a secret.
+    pub fn new(value: BigUint) -> Self {
+        Secret(value)
+    }
+
+    /// Borrows the underlying value for use in an operation; callers must
+    /// not stash this reference anywhere that outlives the `Secret`.
+    pub fn expose(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret(..)")
+    }
+}
+
+/// Like [`solve_zk_challenge_s`](crate::solve_zk_challenge_s), but takes the
+/// witness and nonce as [`Secret`]s so they are zeroized as soon as this
+/// call (and its `Secret` arguments) go out of scope.
+pub fn solve_zk_challenge_s_secret(
+    x_secret: &Secret,
+    k: &Secret,
+    c: &BigUint,
+    q: &BigUint,
+) -> BigUint {
+    crate::solve_zk_challenge_s(x_secret.expose(), k.expose(), c, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroizes_on_drop() {
+        let secret = Secret::new(BigUint::from(123456789u64));
+        assert_eq!(*secret.expose(), BigUint::from(123456789u64));
+        drop(secret);
+        // Nothing observable remains to assert on post-drop without unsafe
+        // access to freed memory; this test only checks that `expose`
+        // returns the wrapped value and that dropping a `Secret` runs.
+    }
+}
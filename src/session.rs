@@ -1,3 +1,6 @@
+use crate::commitment::{point_combine, point_pow};
+use crate::secret::{solve_zk_challenge_s_secret, Secret};
+use crate::transcript::challenge_from_transcript;
 use crate::types::{Error, Group, Point, VerificationParams};
 use crate::traits::{GroupOps, ZkpOps};
 use num_bigint::BigUint;
@@ -15,35 +18,61 @@ pub enum SessionState {
     Finalized,
 }
 
-/// A multi-round session for zero-knowledge proofs
-pub struct Session {
+/// A round's commitments as produced by the prover. The nonce `k` is kept as
+/// a [`Secret`] so it is zeroized as soon as the round is done with.
+struct ProverRound {
+    k: Secret,
+    r1: Point,
+    r2: Point,
+    c: BigUint,
+    s: BigUint,
+}
+
+/// The prover side of a multi-round session. Unlike the old `Session`, this
+/// retains the witness `x_secret` for the session's lifetime so that
+/// `solve_challenge` can answer with a real Chaum-Pedersen response instead
+/// of a value derived from fresh randomness. Both the witness and the
+/// per-round nonces are wrapped in [`Secret`] so they are zeroized once the
+/// session (or round) is dropped, rather than lingering in memory.
+pub struct ProverSession {
     state: SessionState,
     group: Group,
     p: BigUint,
     q: BigUint,
     g: Point,
     h: Point,
+    x_secret: Secret,
     y1: Point,
     y2: Point,
-    rounds: HashMap<usize, (Point, Point, BigUint, BigUint)>,
+    rounds: HashMap<usize, ProverRound>,
     current_round: usize,
 }
 
-impl Session {
-    /// Create a new session
+impl ProverSession {
+    /// Creates a new prover session, sampling the witness `x_secret` and
+    /// deriving the public key pair `y1 = g^x`, `y2 = h^x`. `Group::Secp256k1`
+    /// draws its parameters from [`crate::ecc::Secp256k1GroupOps`] (the real
+    /// 256-bit curve) instead of `get_constants`'s toy fields.
     pub fn new(group: Group) -> Result<Self, Error> {
-        let (p, q, g, h) = get_constants(&group)?;
+        let (p, q, g, h) = match group {
+            Group::Secp256k1 => {
+                let ops = crate::ecc::Secp256k1GroupOps;
+                (ops.prime(), ops.order(), ops.generator(), ops.second_generator())
+            }
+            _ => get_constants(&group)?,
+        };
         let x_secret = BigUint::from_bytes_be(&rand::random::<[u8; 32]>());
         let y1 = g.scale(x_secret.clone());
-        let y2 = h.scale(x_secret);
+        let y2 = h.scale(x_secret.clone());
 
-        Ok(Session {
+        Ok(ProverSession {
             state: SessionState::Initial,
             group,
             p,
             q,
             g,
             h,
+            x_secret: Secret::new(x_secret),
             y1,
             y2,
             rounds: HashMap::new(),
@@ -51,7 +80,16 @@ impl Session {
         })
     }
 
-    /// Start the next round of the session
+    /// Returns the public key pair `(y1, y2)` that a `VerifierSession` needs
+    /// to check this prover's proofs.
+    pub fn public_params(&self) -> (Point, Point) {
+        (self.y1.clone(), self.y2.clone())
+    }
+
+    /// Starts the next round, committing to a fresh nonce `k` and deriving
+    /// this round's challenge from the Fiat-Shamir transcript over
+    /// `(g, h, y1, y2, r1, r2)`, which a `VerifierSession` recomputes
+    /// identically from the commitments it receives.
     pub fn next_round(&mut self) -> Result<(Point, Point), Error> {
         if matches!(self.state, SessionState::Finalized) {
             return Err(Error::InvalidArguments);
@@ -61,77 +99,252 @@ impl Session {
         let k = BigUint::from_bytes_be(&rand::random::<[u8; 32]>());
         let r1 = self.g.scale(k.clone());
         let r2 = self.h.scale(k.clone());
+        let c = challenge_from_transcript(
+            &self.g, &self.h, &self.y1, &self.y2, &r1, &r2, None, &self.q,
+        );
 
-        self.rounds.insert(self.current_round, (r1.clone(), r2.clone(), k, BigUint::from(0u32)));
+        self.rounds.insert(
+            self.current_round,
+            ProverRound {
+                k: Secret::new(k),
+                r1: r1.clone(),
+                r2: r2.clone(),
+                c,
+                s: BigUint::from(0u32),
+            },
+        );
         self.current_round += 1;
 
         Ok((r1, r2))
     }
 
-    /// Solve the challenge for the current round
-    pub fn solve_challenge(&mut self, round: usize, challenge: &BigUint) -> Result<BigUint, Error> {
+    /// Solves this round's challenge using the session's witness, returning
+    /// the response `s` that should be sent to the verifier alongside `r1`,
+    /// `r2`.
+    pub fn solve_challenge(&mut self, round: usize) -> Result<BigUint, Error> {
         if matches!(self.state, SessionState::Finalized) {
             return Err(Error::InvalidArguments);
         }
 
-        let (_, _, k, _) = self.rounds.get_mut(&round)
-            .ok_or(Error::InvalidArguments)?;
+        let data = self.rounds.get_mut(&round).ok_or(Error::InvalidArguments)?;
+        let s = solve_zk_challenge_s_secret(&self.x_secret, &data.k, &data.c, &self.q);
+        data.s = s.clone();
+
+        Ok(s)
+    }
+
+    /// Finalizes the session
+    pub fn finalize(&mut self) -> Result<(), Error> {
+        self.state = SessionState::Finalized;
+        Ok(())
+    }
+
+    /// Gets the session state
+    pub fn state(&self) -> &SessionState {
+        &self.state
+    }
+
+    /// Gets the number of rounds
+    pub fn round_count(&self) -> usize {
+        self.current_round
+    }
+
+    /// Converts the session to JSON, excluding the witness.
+    pub fn to_json(&self) -> Result<String, Error> {
+        let rounds: Vec<_> = self.rounds.iter()
+            .map(|(round, data)| {
+                json!({
+                    "round": round,
+                    "r1": data.r1.serialize(),
+                    "r2": data.r2.serialize(),
+                    "c": data.c.to_string(),
+                    "s": data.s.to_string(),
+                })
+            })
+            .collect();
+
+        let json = json!({
+            "state": format!("{:?}", self.state),
+            "group": self.group,
+            "p": self.p.to_string(),
+            "q": self.q.to_string(),
+            "g": self.g.serialize(),
+            "h": self.h.serialize(),
+            "y1": self.y1.serialize(),
+            "y2": self.y2.serialize(),
+            "rounds": rounds,
+            "current_round": self.current_round,
+        });
+
+        to_string(&json).map_err(|e| Error::InvalidSerialization(e.to_string()))
+    }
+}
 
-        let s = solve_zk_challenge_s(
-            &BigUint::from_bytes_be(&rand::random::<[u8; 32]>()),
-            k,
-            challenge,
-            &self.q,
+/// A round's commitments and response as received by the verifier.
+struct VerifierRound {
+    r1: Point,
+    r2: Point,
+    c: BigUint,
+    s: BigUint,
+}
+
+/// The verifier side of a multi-round session. Holds only the public
+/// parameters `{p, q, g, h, y1, y2}` and accumulates the `(r1, r2, c, s)`
+/// tuples received from a `ProverSession`, never the witness.
+pub struct VerifierSession {
+    state: SessionState,
+    group: Group,
+    p: BigUint,
+    q: BigUint,
+    g: Point,
+    h: Point,
+    y1: Point,
+    y2: Point,
+    rounds: HashMap<usize, VerifierRound>,
+}
+
+impl VerifierSession {
+    /// Creates a verifier session bound to a prover's public key pair. See
+    /// [`ProverSession::new`] for the `Group::Secp256k1` special case.
+    pub fn new(group: Group, y1: Point, y2: Point) -> Result<Self, Error> {
+        let (p, q, g, h) = match group {
+            Group::Secp256k1 => {
+                let ops = crate::ecc::Secp256k1GroupOps;
+                (ops.prime(), ops.order(), ops.generator(), ops.second_generator())
+            }
+            _ => get_constants(&group)?,
+        };
+
+        Ok(VerifierSession {
+            state: SessionState::Initial,
+            group,
+            p,
+            q,
+            g,
+            h,
+            y1,
+            y2,
+            rounds: HashMap::new(),
+        })
+    }
+
+    /// Records a round's commitments and response, deriving the challenge
+    /// from the same transcript the prover used so the two sides can never
+    /// disagree on `c`.
+    pub fn receive_round(
+        &mut self,
+        round: usize,
+        r1: Point,
+        r2: Point,
+        s: BigUint,
+    ) -> Result<(), Error> {
+        if matches!(self.state, SessionState::Finalized) {
+            return Err(Error::InvalidArguments);
+        }
+
+        self.state = SessionState::Active;
+        let c = challenge_from_transcript(
+            &self.g, &self.h, &self.y1, &self.y2, &r1, &r2, None, &self.q,
         );
+        self.rounds.insert(round, VerifierRound { r1, r2, c, s });
 
-        Ok(s)
+        Ok(())
     }
 
-    /// Verify a proof for a specific round
+    /// Verifies the stored proof for a specific round.
     pub fn verify_round(&self, round: usize) -> Result<bool, Error> {
-        let (r1, r2, _, s) = self.rounds.get(&round)
-            .ok_or(Error::InvalidArguments)?;
+        let data = self.rounds.get(&round).ok_or(Error::InvalidArguments)?;
 
         let params = VerificationParams {
-            r1: r1.clone(),
-            r2: r2.clone(),
+            r1: data.r1.clone(),
+            r2: data.r2.clone(),
             y1: self.y1.clone(),
             y2: self.y2.clone(),
             g: self.g.clone(),
             h: self.h.clone(),
-            c: BigUint::from_bytes_be(&rand::random::<[u8; 32]>()),
-            s: s.clone(),
+            c: data.c.clone(),
+            s: data.s.clone(),
             p: self.p.clone(),
         };
 
         verify(&params)
     }
 
-    /// Finalize the session
+    /// Finalizes the session
     pub fn finalize(&mut self) -> Result<(), Error> {
         self.state = SessionState::Finalized;
         Ok(())
     }
 
-    /// Get the session state
+    /// Gets the session state
     pub fn state(&self) -> &SessionState {
         &self.state
     }
 
-    /// Get the number of rounds
+    /// Gets the number of rounds recorded so far
     pub fn round_count(&self) -> usize {
-        self.current_round
+        self.rounds.len()
     }
 
-    /// Convert the session to JSON
+    /// Batch-verifies every recorded round in a single aggregated check
+    /// instead of verifying each one separately. Draws an independent random
+    /// weight `ρ_i` per round and checks the two aggregated equations
+    /// `Π r1_i^{ρ_i} == g^{Σ ρ_i·s_i} · y1^{Σ ρ_i·c_i}` and the analogous one
+    /// for `(h, y2, r2)`, so `n` rounds cost roughly one multi-exponentiation
+    /// each instead of `n` separate ones.
+    pub fn verify_all(&self) -> Result<bool, Error> {
+        if self.rounds.is_empty() {
+            return Ok(true);
+        }
+
+        let mut agg_s = BigUint::from(0u32);
+        let mut agg_c = BigUint::from(0u32);
+        let mut lhs1: Option<Point> = None;
+        let mut lhs2: Option<Point> = None;
+
+        for data in self.rounds.values() {
+            let rho = BigUint::from_bytes_be(&rand::random::<[u8; 32]>()) % &self.q;
+
+            agg_s = (agg_s + &rho * &data.s) % &self.q;
+            agg_c = (agg_c + &rho * &data.c) % &self.q;
+
+            let r1_rho = point_pow(&data.r1, &rho, &self.p)?;
+            let r2_rho = point_pow(&data.r2, &rho, &self.p)?;
+
+            lhs1 = Some(match lhs1 {
+                Some(acc) => point_combine(&acc, &r1_rho, &self.p)?,
+                None => r1_rho,
+            });
+            lhs2 = Some(match lhs2 {
+                Some(acc) => point_combine(&acc, &r2_rho, &self.p)?,
+                None => r2_rho,
+            });
+        }
+
+        let rhs1 = point_combine(
+            &point_pow(&self.g, &agg_s, &self.p)?,
+            &point_pow(&self.y1, &agg_c, &self.p)?,
+            &self.p,
+        )?;
+        let rhs2 = point_combine(
+            &point_pow(&self.h, &agg_s, &self.p)?,
+            &point_pow(&self.y2, &agg_c, &self.p)?,
+            &self.p,
+        )?;
+
+        Ok(lhs1.unwrap() == rhs1 && lhs2.unwrap() == rhs2)
+    }
+
+    /// Converts the session to JSON.
     pub fn to_json(&self) -> Result<String, Error> {
         let rounds: Vec<_> = self.rounds.iter()
-            .map(|(round, (r1, r2, _, s))| {
+            .map(|(round, data)| {
                 json!({
                     "round": round,
-                    "r1": r1.serialize(),
-                    "r2": r2.serialize(),
-                    "s": s.to_string(),
+                    "r1": data.r1.serialize(),
+                    "r2": data.r2.serialize(),
+                    "c": data.c.to_string(),
+                    "s": data.s.to_string(),
                 })
             })
             .collect();
@@ -146,9 +359,8 @@ impl Session {
             "y1": self.y1.serialize(),
             "y2": self.y2.serialize(),
             "rounds": rounds,
-            "current_round": self.current_round,
         });
 
         to_string(&json).map_err(|e| Error::InvalidSerialization(e.to_string()))
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,304 @@
+//! Solidity codegen for verifying Chaum-Pedersen proofs on-chain.
+//!
+//! [`render_verifying_key`] and [`render_verifier_contract`] emit Solidity
+//! source reproducing the same two checks [`crate::registry::verify_with_curve`]
+//! (and the hand-written `ZkpOps::verify_proof` impls) perform off-chain:
+//! `r1 == g^s·y1^c` and `r2 == h^s·y2^c`. They can be rendered together (one
+//! contract with the key inlined) or the key alone, for callers who want to
+//! deploy a single verifying-key contract shared by several verifier
+//! versions. [`encode_calldata`] is the Rust-side counterpart: it packs a
+//! [`VerificationParams`] into the fixed-width big-endian byte layout the
+//! generated `verify` function expects to decode.
+//!
+//! Scope note: the EVM only has a generic modular-exponentiation precompile
+//! (`0x05`, "MODEXP"), which is enough to implement the scalar group's
+//! `g^s mod p` directly on-chain. It has no generic elliptic-curve
+//! precompile for secp256k1 (only the fixed alt_bn128 pairing-friendly
+//! curve at `0x06`/`0x07`/`0x08`), so the EC-group contract instead calls
+//! out to an `IEllipticCurve` interface and leaves wiring in a concrete
+//! secp256k1 library (e.g. one built on the `ecrecover` precompile trick,
+//! or a pure-Solidity implementation) to the integrator. The Ristretto255
+//! group (behind the crate's `curve25519` feature) is in the same
+//! position — no EVM precompile for curve25519 either — so its generated
+//! contract calls out to an analogous `IRistretto` interface.
+
+use crate::types::{Error, Point, VerificationParams};
+use num_bigint::BigUint;
+
+/// Renders the "verifying key" fragment: a library contract exposing the
+/// group's fixed generators `g`, `h` and modulus `p` as Solidity constants,
+/// so a verifier contract can `import` it instead of hardcoding the
+/// parameters for every deployed version.
+pub fn render_verifying_key(g: &Point, h: &Point, p: &BigUint) -> Result<String, Error> {
+    #[cfg(feature = "curve25519")]
+    if let (Point::Ristretto(_), Point::Ristretto(_)) = (g, h) {
+        return render_ristretto_verifying_key(g, h);
+    }
+
+    let (gx, gy) = ec_point_or_scalar(g)?;
+    let (hx, hy) = ec_point_or_scalar(h)?;
+
+    Ok(format!(
+        "// SPDX-License-Identifier: MIT\n\
+         pragma solidity ^0.8.19;\n\
+         \n\
+         /// Fixed Chaum-Pedersen parameters, generated by cpzkp's `solidity` codegen.\n\
+         library VerifyingKey {{\n    \
+             uint256 internal constant P = {p};\n    \
+             uint256 internal constant GX = {gx};\n    \
+             uint256 internal constant GY = {gy};\n    \
+             uint256 internal constant HX = {hx};\n    \
+             uint256 internal constant HY = {hy};\n\
+         }}\n",
+        p = p,
+        gx = gx,
+        gy = gy,
+        hx = hx,
+        hy = hy,
+    ))
+}
+
+/// Renders a standalone verifier contract with the verifying key inlined.
+/// For the scalar group it uses the EVM's MODEXP precompile (`0x05`) for
+/// `g^s mod p` and `y1^c mod p`; for `ECPoint` and Ristretto generators it
+/// calls out to the `IEllipticCurve`/`IRistretto` interfaces instead.
+pub fn render_verifier_contract(g: &Point, h: &Point, p: &BigUint) -> Result<String, Error> {
+    match (g, h) {
+        (Point::Scalar(_), Point::Scalar(_)) => render_scalar_verifier(g, h, p),
+        (Point::ECPoint(_, _), Point::ECPoint(_, _)) => render_ecpoint_verifier(g, h, p),
+        #[cfg(feature = "curve25519")]
+        (Point::Ristretto(_), Point::Ristretto(_)) => render_ristretto_verifier(g, h),
+        _ => Err(Error::PointTypeMismatch),
+    }
+}
+
+fn render_scalar_verifier(g: &Point, h: &Point, p: &BigUint) -> Result<String, Error> {
+    let key = render_verifying_key(g, h, p)?;
+    Ok(format!(
+        "{key}\n\
+         /// Verifies a Chaum-Pedersen proof over the scalar group `Z_p^*`.\n\
+         contract ChaumPedersenScalarVerifier {{\n    \
+             /// Computes `base^exp mod VerifyingKey.P` via the MODEXP precompile.\n    \
+             function _modexp(uint256 base, uint256 exp) internal view returns (uint256 result) {{\n        \
+                 uint256 p = VerifyingKey.P;\n        \
+                 assembly {{\n            \
+                     let ptr := mload(0x40)\n            \
+                     mstore(ptr, 0x20)\n            \
+                     mstore(add(ptr, 0x20), 0x20)\n            \
+                     mstore(add(ptr, 0x40), 0x20)\n            \
+                     mstore(add(ptr, 0x60), base)\n            \
+                     mstore(add(ptr, 0x80), exp)\n            \
+                     mstore(add(ptr, 0xa0), p)\n            \
+                     if iszero(staticcall(gas(), 0x05, ptr, 0xc0, ptr, 0x20)) {{ revert(0, 0) }}\n            \
+                     result := mload(ptr)\n        \
+                 }}\n    \
+             }}\n\n    \
+             /// Checks `r1 == g^s·y1^c mod p` and `r2 == h^s·y2^c mod p`.\n    \
+             function verify(\n        \
+                 uint256 r1, uint256 r2,\n        \
+                 uint256 y1, uint256 y2,\n        \
+                 uint256 c, uint256 s\n    \
+             ) external view returns (bool) {{\n        \
+                 uint256 p = VerifyingKey.P;\n        \
+                 uint256 lhs1 = mulmod(_modexp(VerifyingKey.GX, s), _modexp(y1, c), p);\n        \
+                 uint256 lhs2 = mulmod(_modexp(VerifyingKey.HX, s), _modexp(y2, c), p);\n        \
+                 return lhs1 == r1 && lhs2 == r2;\n    \
+             }}\n\
+         }}\n"
+    ))
+}
+
+fn render_ecpoint_verifier(g: &Point, h: &Point, p: &BigUint) -> Result<String, Error> {
+    let key = render_verifying_key(g, h, p)?;
+    Ok(format!(
+        "{key}\n\
+         /// An EC point, as the external `IEllipticCurve` library expects it.\n\
+         struct ECPoint {{ uint256 x; uint256 y; }}\n\n\
+         /// The secp256k1 operations this verifier needs; the EVM has no\n\
+         /// built-in secp256k1 precompile (only alt_bn128's pairing-friendly\n\
+         /// curve at 0x06/0x07/0x08), so this is left for the integrator to\n\
+         /// back with a concrete library.\n\
+         interface IEllipticCurve {{\n    \
+             function ecAdd(ECPoint memory a, ECPoint memory b) external pure returns (ECPoint memory);\n    \
+             function ecMul(ECPoint memory a, uint256 scalar) external pure returns (ECPoint memory);\n\
+         }}\n\n\
+         /// Verifies a Chaum-Pedersen proof over the secp256k1 group.\n\
+         contract ChaumPedersenECVerifier {{\n    \
+             IEllipticCurve public immutable curve;\n\n    \
+             constructor(IEllipticCurve _curve) {{ curve = _curve; }}\n\n    \
+             /// Checks `r1 == s·g + c·y1` and `r2 == s·h + c·y2`.\n    \
+             function verify(\n        \
+                 ECPoint memory r1, ECPoint memory r2,\n        \
+                 ECPoint memory y1, ECPoint memory y2,\n        \
+                 uint256 c, uint256 s\n    \
+             ) external view returns (bool) {{\n        \
+                 ECPoint memory g = ECPoint(VerifyingKey.GX, VerifyingKey.GY);\n        \
+                 ECPoint memory h = ECPoint(VerifyingKey.HX, VerifyingKey.HY);\n        \
+                 ECPoint memory lhs1 = curve.ecAdd(curve.ecMul(g, s), curve.ecMul(y1, c));\n        \
+                 ECPoint memory lhs2 = curve.ecAdd(curve.ecMul(h, s), curve.ecMul(y2, c));\n        \
+                 return lhs1.x == r1.x && lhs1.y == r1.y && lhs2.x == r2.x && lhs2.y == r2.y;\n    \
+             }}\n\
+         }}\n"
+    ))
+}
+
+/// Renders the verifying key for the Ristretto group: `g`/`h` have no `p`
+/// (Ristretto's field prime is the fixed `2^255 - 19`, not a per-system
+/// parameter), so they're embedded as their 32-byte compressed encodings
+/// instead of `(x, y)` coordinates.
+#[cfg(feature = "curve25519")]
+fn render_ristretto_verifying_key(g: &Point, h: &Point) -> Result<String, Error> {
+    let (g_bytes, h_bytes) = match (g, h) {
+        (Point::Ristretto(g), Point::Ristretto(h)) => (g, h),
+        _ => return Err(Error::PointTypeMismatch),
+    };
+
+    Ok(format!(
+        "// SPDX-License-Identifier: MIT\n\
+         pragma solidity ^0.8.19;\n\
+         \n\
+         /// Fixed Chaum-Pedersen parameters over the Ristretto255 group,\n\
+         /// generated by cpzkp's `solidity` codegen.\n\
+         library VerifyingKey {{\n    \
+             bytes32 internal constant G = 0x{g_hex};\n    \
+             bytes32 internal constant H = 0x{h_hex};\n\
+         }}\n",
+        g_hex = hex_string(g_bytes),
+        h_hex = hex_string(h_bytes),
+    ))
+}
+
+/// Renders a standalone verifier contract for the Ristretto group. Like
+/// secp256k1, the EVM has no curve25519/Ristretto precompile, so this
+/// calls out to an `IRistretto` interface rather than implementing scalar
+/// multiplication in Solidity.
+#[cfg(feature = "curve25519")]
+fn render_ristretto_verifier(g: &Point, h: &Point) -> Result<String, Error> {
+    let key = render_ristretto_verifying_key(g, h)?;
+    Ok(format!(
+        "{key}\n\
+         /// The Ristretto255 operations this verifier needs; left for the\n\
+         /// integrator to back with a concrete library, since the EVM has no\n\
+         /// built-in Ristretto/curve25519 precompile.\n\
+         interface IRistretto {{\n    \
+             function add(bytes32 a, bytes32 b) external pure returns (bytes32);\n    \
+             function scalarMul(bytes32 point, uint256 scalar) external pure returns (bytes32);\n\
+         }}\n\n\
+         /// Verifies a Chaum-Pedersen proof over the Ristretto255 group.\n\
+         contract ChaumPedersenRistrettoVerifier {{\n    \
+             IRistretto public immutable curve;\n\n    \
+             constructor(IRistretto _curve) {{ curve = _curve; }}\n\n    \
+             /// Checks `r1 == s·g + c·y1` and `r2 == s·h + c·y2`.\n    \
+             function verify(\n        \
+                 bytes32 r1, bytes32 r2,\n        \
+                 bytes32 y1, bytes32 y2,\n        \
+                 uint256 c, uint256 s\n    \
+             ) external view returns (bool) {{\n        \
+                 bytes32 lhs1 = curve.add(curve.scalarMul(VerifyingKey.G, s), curve.scalarMul(y1, c));\n        \
+                 bytes32 lhs2 = curve.add(curve.scalarMul(VerifyingKey.H, s), curve.scalarMul(y2, c));\n        \
+                 return lhs1 == r1 && lhs2 == r2;\n    \
+             }}\n\
+         }}\n"
+    ))
+}
+
+/// Hex-encodes `bytes` without a `0x` prefix (callers splice that in).
+#[cfg(feature = "curve25519")]
+fn hex_string(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Extracts `(x, y)`-shaped decimal strings for a point usable in the
+/// generated Solidity: `Point::Scalar(x)` is rendered as `(x, 0)` (the
+/// scalar group's `verify` function never reads the second coordinate),
+/// and `Point::ECPoint(x, y)` as-is.
+fn ec_point_or_scalar(point: &Point) -> Result<(String, String), Error> {
+    match point {
+        Point::Scalar(x) => Ok((x.to_string(), "0".to_string())),
+        Point::ECPoint(x, y) => Ok((x.to_string(), y.to_string())),
+        _ => Err(Error::PointTypeMismatch),
+    }
+}
+
+/// Packs `params` into the fixed-width big-endian calldata layout the
+/// generated `verify` function expects: `r1 ‖ r2 ‖ y1 ‖ y2 ‖ c ‖ s`, each
+/// field padded to 32 bytes (`ECPoint`s contribute their `x` then `y`
+/// coordinate, each 32 bytes; `Scalar`s contribute a single 32-byte word).
+pub fn encode_calldata(params: &VerificationParams) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::with_capacity(6 * 32);
+    for point in [&params.r1, &params.r2, &params.y1, &params.y2] {
+        match point {
+            Point::Scalar(x) => out.extend_from_slice(&crate::to_fixed_width_be(x, 32)),
+            Point::ECPoint(x, y) => {
+                out.extend_from_slice(&crate::to_fixed_width_be(x, 32));
+                out.extend_from_slice(&crate::to_fixed_width_be(y, 32));
+            }
+            #[cfg(feature = "curve25519")]
+            Point::Ristretto(bytes) => out.extend_from_slice(bytes),
+            _ => return Err(Error::PointTypeMismatch),
+        }
+    }
+    out.extend_from_slice(&crate::to_fixed_width_be(&params.c, 32));
+    out.extend_from_slice(&crate::to_fixed_width_be(&params.s, 32));
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_constants_scalar;
+
+    #[test]
+    fn render_verifying_key_embeds_the_scalar_generators() {
+        let (p, _, g, h) = get_constants_scalar();
+        let rendered = render_verifying_key(&g, &h, &p).unwrap();
+
+        assert!(rendered.contains("library VerifyingKey"));
+        assert!(rendered.contains(&p.to_string()));
+        let (gx, _) = ec_point_or_scalar(&g).unwrap();
+        let (hx, _) = ec_point_or_scalar(&h).unwrap();
+        assert!(rendered.contains(&format!("GX = {};", gx)));
+        assert!(rendered.contains(&format!("HX = {};", hx)));
+    }
+
+    #[test]
+    fn render_verifier_contract_picks_the_scalar_template() {
+        let (p, _, g, h) = get_constants_scalar();
+        let rendered = render_verifier_contract(&g, &h, &p).unwrap();
+
+        assert!(rendered.contains("ChaumPedersenScalarVerifier"));
+        assert!(rendered.contains("0x05"));
+    }
+
+    #[test]
+    fn encode_calldata_packs_six_32_byte_words_for_scalar_params() {
+        let (p, q, g, h) = get_constants_scalar();
+        let x_secret = BigUint::from(1234u32);
+        let k = BigUint::from(5678u32);
+        let c = BigUint::from(910u32) % &q;
+
+        let (y1, y2) = crate::exponentiates_points(&x_secret, &g, &h, &p).unwrap();
+        let (r1, r2) = crate::exponentiates_points(&k, &g, &h, &p).unwrap();
+        let s = crate::solve_zk_challenge_s(&x_secret, &k, &c, &q);
+
+        let params = VerificationParams { r1, r2, y1, y2, g, h, c, s, p };
+        let calldata = encode_calldata(&params).unwrap();
+
+        assert_eq!(calldata.len(), 6 * 32);
+    }
+
+    #[cfg(feature = "curve25519")]
+    #[test]
+    fn render_verifier_contract_picks_the_ristretto_template() {
+        use crate::curve25519::Curve25519Group;
+        use crate::traits::GroupOps;
+
+        let group = Curve25519Group;
+        let g = group.generator();
+        let h = group.second_generator();
+        let rendered = render_verifier_contract(&g, &h, &BigUint::from(0u32)).unwrap();
+
+        assert!(rendered.contains("ChaumPedersenRistrettoVerifier"));
+        assert!(rendered.contains("IRistretto"));
+    }
+}
@@ -52,6 +52,50 @@ pub trait ZkpOps {
         &self,
         params: &crate::types::VerificationParams,
     ) -> Result<bool, Error>;
+
+    /// Derive a Fiat-Shamir challenge bound to the statement being proven,
+    /// rather than an independently-drawn random one (see
+    /// [`generate_challenge`](ZkpOps::generate_challenge)'s doc comment).
+    /// Non-interactive callers should use this instead of
+    /// `generate_challenge`; the `ProverSession`/`VerifierSession` flow in
+    /// [`crate::session`] derives its challenges this way too.
+    #[allow(clippy::too_many_arguments)]
+    fn challenge_from_transcript(
+        &self,
+        g: &Point,
+        h: &Point,
+        y1: &Point,
+        y2: &Point,
+        r1: &Point,
+        r2: &Point,
+        message: Option<&[u8]>,
+    ) -> BigUint;
+
+    /// Verifies many proofs over this group's parameters in a single
+    /// randomized multi-exponentiation, instead of calling
+    /// [`verify_proof`](ZkpOps::verify_proof) once per proof. See
+    /// [`crate::batch::verify_batch`] for the algorithm; this default method
+    /// just forwards to it, since the check doesn't depend on which
+    /// `ZkpOps` impl is asking. Callers who know their proofs all share one
+    /// `g`/`h`/`p` should prefer [`verify_batch_shared_generators`](ZkpOps::verify_batch_shared_generators)
+    /// instead for the cheaper fast path.
+    fn verify_batch(&self, proofs: &[crate::types::VerificationParams]) -> Result<bool, Error> {
+        crate::batch::verify_batch(proofs)
+    }
+
+    /// Like [`verify_batch`](ZkpOps::verify_batch), but for proofs that all
+    /// share one `g`/`h`/`p` (e.g. many authentication attempts against one
+    /// system's parameters), cutting the batch's roughly `8N` scalar
+    /// multiplications to `4N` plus two fixed-base ones. See
+    /// [`crate::batch::verify_batch_shared_generators`] for the algorithm.
+    /// Exposed through the PyO3 bindings (`verify_proof_batch`) for callers
+    /// verifying streams of proofs, e.g. authentication servers.
+    fn verify_batch_shared_generators(
+        &self,
+        proofs: &[crate::types::VerificationParams],
+    ) -> Result<bool, Error> {
+        crate::batch::verify_batch_shared_generators(proofs)
+    }
 }
 
 /// Trait for point operations
@@ -64,10 +108,14 @@ pub trait PointOps {
     
     /// Check if a point is on the curve
     fn is_on_curve(&self) -> bool;
-    
+
     /// Double a point
     fn double(&self) -> Point;
-    
+
     /// Scale a point by a scalar
     fn scale(&self, scalar: BigUint) -> Point;
+
+    /// Add another point to this one, with `Point::Identity` as the neutral
+    /// element.
+    fn add(&self, other: &Point) -> Point;
 } 
\ No newline at end of file
@@ -37,12 +37,16 @@ impl std::error::Error for Error {}
 ///
 /// The protocol can operate in either:
 /// - A scalar (multiplicative) group of integers modulo a prime
-/// - The secp256k1 elliptic curve group
-#[derive(Debug, Default)]
+/// - The secp256k1 elliptic curve group, via `EllipticCurveGroup`'s 14-bit
+///   toy field
+/// - The real 256-bit secp256k1 parameters, via
+///   [`Secp256k1GroupOps`](crate::ecc::Secp256k1GroupOps)
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum Group {
     #[default]
     Scalar,
     EllipticCurve,
+    Secp256k1,
 }
 
 /// Structure to represent elements in the cyclic group.
@@ -50,10 +54,16 @@ pub enum Group {
 /// Points can be either:
 /// - Scalar: A single value representing an element in the multiplicative group
 /// - ECPoint: An (x,y) coordinate pair representing a point on the elliptic curve
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Point {
     Scalar(BigUint),
     ECPoint(BigUint, BigUint),
+    /// A compressed Ristretto255 point, used by the cofactor-safe
+    /// `curve25519` backend (see [`crate::curve25519`]).
+    Ristretto([u8; 32]),
+    /// The point at infinity, the neutral element of an elliptic-curve
+    /// group (see [`crate::ecc::EllipticCurveGroup`]'s `add`/`double`/`scale`).
+    Identity,
 }
 
 /// Parameters for verification of a zero-knowledge proof
@@ -84,6 +94,14 @@ impl fmt::Display for Point {
         match self {
             Point::Scalar(x) => write!(f, "Point::Scalar({})", x),
             Point::ECPoint(x, y) => write!(f, "Point::ECPoint({}, {})", x, y),
+            Point::Ristretto(bytes) => {
+                write!(f, "Point::Ristretto(")?;
+                for byte in bytes {
+                    write!(f, "{:02x}", byte)?;
+                }
+                write!(f, ")")
+            }
+            Point::Identity => write!(f, "Point::Identity"),
         }
     }
 } 
\ No newline at end of file
@@ -1,5 +1,5 @@
 use wasm_bindgen::prelude::*;
-use cpzkp::{Group, Point, get_constants, solve_zk_challenge_s, Error};
+use cpzkp::{Group, Point, Secret, get_constants, solve_zk_challenge_s_secret, challenge_from_transcript, Error};
 use num_bigint::BigUint;
 use serde_json::{json, to_string};
 
@@ -12,6 +12,7 @@ pub struct KeyPair {
     h: Point,
     y1: Point,
     y2: Point,
+    x_secret: Secret,
 }
 
 #[wasm_bindgen]
@@ -23,15 +24,17 @@ impl KeyPair {
             "elliptic" => Group::EllipticCurve,
             #[cfg(feature = "curve25519")]
             "curve25519" => Group::Curve25519,
+            "babyjubjub" => Group::BabyJubjub,
+            "secp256k1" => Group::Secp256k1,
             _ => return Err(JsValue::from_str("Invalid group type")),
         };
 
         let (p, q, g, h) = get_constants(&group)
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
-        
+
         let x_secret = BigUint::from_bytes_be(&rand::random::<[u8; 32]>());
         let y1 = g.scale(x_secret.clone());
-        let y2 = h.scale(x_secret);
+        let y2 = h.scale(x_secret.clone());
 
         Ok(KeyPair {
             group,
@@ -41,6 +44,7 @@ impl KeyPair {
             h,
             y1,
             y2,
+            x_secret: Secret::new(x_secret),
         })
     }
 
@@ -50,10 +54,10 @@ impl KeyPair {
             "group": self.group,
             "p": self.p.to_string(),
             "q": self.q.to_string(),
-            "g": self.g.serialize(),
-            "h": self.h.serialize(),
-            "y1": self.y1.serialize(),
-            "y2": self.y2.serialize(),
+            "g": self.g.to_base16(),
+            "h": self.h.to_base16(),
+            "y1": self.y1.to_base16(),
+            "y2": self.y2.to_base16(),
         });
         to_string(&json).map_err(|e| JsValue::from_str(&e.to_string()))
     }
@@ -81,13 +85,23 @@ impl Proof {
         let r1 = keypair.g.scale(k.clone());
         let r2 = keypair.h.scale(k.clone());
 
-        let c = BigUint::from_bytes_be(&rand::random::<[u8; 32]>());
-        let s = solve_zk_challenge_s(
-            &BigUint::from_bytes_be(message.as_bytes()),
-            &k,
-            &c,
+        // Fiat-Shamir: derive `c` from the transcript of public parameters and
+        // commitments instead of drawing it independently, so the prover's `s`
+        // is guaranteed to match what a verifier recomputing `c` will check.
+        // `s` itself must be solved against the keypair's actual witness
+        // `x_secret`, not the message bytes — the message only binds the
+        // transcript, it was never the secret being proven knowledge of.
+        let c = challenge_from_transcript(
+            &keypair.g,
+            &keypair.h,
+            &keypair.y1,
+            &keypair.y2,
+            &r1,
+            &r2,
+            Some(message.as_bytes()),
             &keypair.q,
         );
+        let s = solve_zk_challenge_s_secret(&keypair.x_secret, &Secret::new(k), &c, &keypair.q);
 
         Ok(Proof {
             group: keypair.group.clone(),
@@ -107,12 +121,12 @@ impl Proof {
     pub fn to_json(&self) -> Result<String, JsValue> {
         let json = json!({
             "group": self.group,
-            "r1": self.r1.serialize(),
-            "r2": self.r2.serialize(),
-            "y1": self.y1.serialize(),
-            "y2": self.y2.serialize(),
-            "g": self.g.serialize(),
-            "h": self.h.serialize(),
+            "r1": self.r1.to_base16(),
+            "r2": self.r2.to_base16(),
+            "y1": self.y1.to_base16(),
+            "y2": self.y2.to_base16(),
+            "g": self.g.to_base16(),
+            "h": self.h.to_base16(),
             "c": self.c.to_string(),
             "s": self.s.to_string(),
             "p": self.p.to_string(),
@@ -0,0 +1,251 @@
+//! Fixed-window (k-ary) exponentiation, as a faster alternative to the
+//! naive square-and-multiply `secp256k1::Point::scale`/`BigUint::modpow`
+//! paths `exponentiates_points` relies on, for the realistic ~256-bit
+//! exponents the benchmarks in `benches/zkp_benchmarks.rs` are meant to
+//! exercise.
+//!
+//! The window width defaults to one auto-chosen from the exponent's bit
+//! length (`window_width`), but callers repeating many exponentiations of
+//! the same base — e.g. a fixed generator across many proofs — should
+//! build a [`FixedBaseTable`] once and reuse it, so the table-building cost
+//! is paid a single time instead of per call.
+//!
+//! Note: `windowed_pow`/`FixedBaseTable::pow` return
+//! `Error::InvalidArguments` for an exponent of `0` rather than
+//! `Point::Identity` (the `point_pow`/`point_combine` helpers they build on
+//! do not handle the identity); callers needing that should special-case it.
+
+use crate::commitment::{point_combine, point_pow};
+use crate::types::{Error, Point};
+use num_bigint::BigUint;
+
+/// Chooses a window width for an exponent of the given bit length, growing
+/// roughly as `log2(bit_length)` so the table stays small while still
+/// cutting the number of multiplications for large exponents (`w≈5` for a
+/// 256-bit exponent, matching the usual rule of thumb for k-ary
+/// exponentiation).
+pub fn window_width(bit_length: u64) -> usize {
+    match bit_length {
+        0..=32 => 2,
+        33..=128 => 4,
+        _ => 5,
+    }
+}
+
+/// Precomputes `base^1, base^2, …, base^{2^w - 1}` (`w` from
+/// `window_width`) for windowed exponentiation of a single, repeatedly
+/// used base.
+pub struct FixedBaseTable {
+    window: usize,
+    table: Vec<Point>,
+    p: BigUint,
+}
+
+impl FixedBaseTable {
+    /// Builds the table for `base`, sized for exponents up to
+    /// `max_exp_bits` bits.
+    pub fn new(base: &Point, max_exp_bits: u64, p: &BigUint) -> Result<Self, Error> {
+        let window = window_width(max_exp_bits);
+        let size = (1usize << window) - 1;
+
+        let mut table = Vec::with_capacity(size);
+        table.push(base.clone());
+        for j in 1..size {
+            let next = point_combine(&table[j - 1], base, p)?;
+            table.push(next);
+        }
+
+        Ok(FixedBaseTable {
+            window,
+            table,
+            p: p.clone(),
+        })
+    }
+
+    /// Computes `base^exp` using the precomputed table. `exp` must be
+    /// nonzero (see the module's note on an exponent of `0`).
+    pub fn pow(&self, exp: &BigUint) -> Result<Point, Error> {
+        windowed_pow_with_table(exp, &self.table, self.window, &self.p)
+    }
+}
+
+/// One-shot windowed exponentiation `base^exp`, auto-sizing the window
+/// from `exp`'s own bit length. Prefer [`FixedBaseTable`] when `base` is
+/// reused across many calls.
+pub fn windowed_pow(base: &Point, exp: &BigUint, p: &BigUint) -> Result<Point, Error> {
+    let window = window_width(exp.bits());
+    let size = (1usize << window) - 1;
+
+    let mut table = Vec::with_capacity(size);
+    table.push(base.clone());
+    for j in 1..size {
+        let next = point_combine(&table[j - 1], base, p)?;
+        table.push(next);
+    }
+
+    windowed_pow_with_table(exp, &table, window, p)
+}
+
+fn windowed_pow_with_table(
+    exp: &BigUint,
+    table: &[Point],
+    window: usize,
+    p: &BigUint,
+) -> Result<Point, Error> {
+    if *exp == BigUint::from(0u32) {
+        return Err(Error::InvalidArguments);
+    }
+
+    let bit_len = exp.bits() as usize;
+    let num_windows = bit_len.div_ceil(window);
+
+    let mut acc: Option<Point> = None;
+    for w in (0..num_windows).rev() {
+        if let Some(current) = acc.take() {
+            let mut squared = current;
+            for _ in 0..window {
+                squared = point_pow(&squared, &BigUint::from(2u32), p)?;
+            }
+            acc = Some(squared);
+        }
+
+        let digit = extract_window(exp, w, window);
+        if digit != 0 {
+            let factor = &table[digit - 1];
+            acc = Some(match acc {
+                Some(current) => point_combine(&current, factor, p)?,
+                None => factor.clone(),
+            });
+        }
+    }
+
+    acc.ok_or(Error::InvalidArguments)
+}
+
+/// Extracts the `window`-bit digit at window index `w` (0 = least
+/// significant window) from `exp`.
+fn extract_window(exp: &BigUint, w: usize, window: usize) -> usize {
+    let shifted = exp >> (w * window);
+    let mask = (BigUint::from(1u32) << window) - BigUint::from(1u32);
+    let digit = shifted & mask;
+    digit.to_u32_digits().first().copied().unwrap_or(0) as usize
+}
+
+/// Answers fixed-base scalar multiplication against a generator whose table
+/// has already been built, so repeated calls (e.g. a server checking many
+/// proofs against the same system parameters) pay the table-construction
+/// cost once instead of on every call. See [`FixedBaseGenerators`] for the
+/// `Point::Scalar`/`Point::ECPoint` implementation and
+/// [`crate::curve25519::Curve25519FixedBase`] for the Ristretto one.
+pub trait FixedBaseMul {
+    /// Computes `g^scalar` (or `scalar·g`) against the precomputed `g` table.
+    fn scalar_mul_base(&self, scalar: &BigUint) -> Result<Point, Error>;
+    /// Computes `h^scalar` (or `scalar·h`) against the precomputed `h` table.
+    fn scalar_mul_second_base(&self, scalar: &BigUint) -> Result<Point, Error>;
+}
+
+/// Precomputed [`FixedBaseTable`]s for a group's two standard generators
+/// `g`/`h`, for the `Point::Scalar` and `Point::ECPoint` groups (both are
+/// served by the same generic `point_pow`/`point_combine` machinery
+/// `FixedBaseTable` is built on).
+pub struct FixedBaseGenerators {
+    g_table: FixedBaseTable,
+    h_table: FixedBaseTable,
+}
+
+impl FixedBaseGenerators {
+    /// Builds both tables up front, sized for exponents up to `max_exp_bits`
+    /// bits (typically the bit length of the group order `q`).
+    pub fn new(g: &Point, h: &Point, max_exp_bits: u64, p: &BigUint) -> Result<Self, Error> {
+        Ok(FixedBaseGenerators {
+            g_table: FixedBaseTable::new(g, max_exp_bits, p)?,
+            h_table: FixedBaseTable::new(h, max_exp_bits, p)?,
+        })
+    }
+
+    /// Builds tables for [`crate::get_constants_scalar`]'s fixed `g`/`h`, so
+    /// a caller repeatedly calling [`crate::exponentiates_points`] against
+    /// the scalar group can pay the table-construction cost once.
+    pub fn for_scalar_group() -> Self {
+        let (p, q, g, h) = crate::get_constants_scalar();
+        FixedBaseGenerators::new(&g, &h, q.bits(), &p)
+            .expect("the scalar group's own fixed g/h always build a table")
+    }
+
+    /// Builds tables for [`crate::get_constants_elliptic_curve`]'s fixed
+    /// `g`/`h`, the elliptic-curve analogue of [`Self::for_scalar_group`].
+    pub fn for_elliptic_curve_group() -> Result<Self, Error> {
+        let (p, q, g, h) = crate::get_constants_elliptic_curve()?;
+        FixedBaseGenerators::new(&g, &h, q.bits(), &p)
+    }
+}
+
+impl FixedBaseMul for FixedBaseGenerators {
+    fn scalar_mul_base(&self, scalar: &BigUint) -> Result<Point, Error> {
+        self.g_table.pow(scalar)
+    }
+
+    fn scalar_mul_second_base(&self, scalar: &BigUint) -> Result<Point, Error> {
+        self.h_table.pow(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment::point_pow;
+    use crate::get_constants_scalar;
+
+    #[test]
+    fn windowed_pow_matches_modpow() {
+        let (p, _, g, _) = get_constants_scalar();
+        let exp = BigUint::from(123456789u64);
+
+        let expected = point_pow(&g, &exp, &p).unwrap();
+        let actual = windowed_pow(&g, &exp, &p).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn fixed_base_table_matches_one_shot() {
+        let (p, _, g, _) = get_constants_scalar();
+        let exp = BigUint::from(987654321u64);
+
+        let table = FixedBaseTable::new(&g, 32, &p).unwrap();
+        let via_table = table.pow(&exp).unwrap();
+        let via_one_shot = windowed_pow(&g, &exp, &p).unwrap();
+
+        assert_eq!(via_table, via_one_shot);
+    }
+
+    #[test]
+    fn fixed_base_generators_match_one_shot_for_both_generators() {
+        let (p, _, g, h) = get_constants_scalar();
+        let exp = BigUint::from(424242u64);
+
+        let fixed = FixedBaseGenerators::new(&g, &h, 32, &p).unwrap();
+
+        assert_eq!(
+            fixed.scalar_mul_base(&exp).unwrap(),
+            windowed_pow(&g, &exp, &p).unwrap()
+        );
+        assert_eq!(
+            fixed.scalar_mul_second_base(&exp).unwrap(),
+            windowed_pow(&h, &exp, &p).unwrap()
+        );
+    }
+
+    #[test]
+    fn for_scalar_group_matches_one_shot() {
+        let (p, _, g, _) = get_constants_scalar();
+        let exp = BigUint::from(13579u64);
+
+        let fixed = FixedBaseGenerators::for_scalar_group();
+
+        assert_eq!(
+            fixed.scalar_mul_base(&exp).unwrap(),
+            windowed_pow(&g, &exp, &p).unwrap()
+        );
+    }
+}